@@ -0,0 +1,164 @@
+use crate::disjoint_set::DisjointSet;
+
+/// A step of a `Dendrogram`, where two clusters are merged into a new cluster.
+///
+/// The clusters `0..n` are the single points, and the cluster made by the `i`-th merge is `n + i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merge<W> {
+	left: usize,
+	right: usize,
+	distance: W,
+	size: usize,
+}
+
+impl<W> Merge<W> {
+	/// Get the merged cluster with the lower id.
+	pub fn left(&self) -> usize { self.left }
+
+	/// Get the merged cluster with the higher id.
+	pub fn right(&self) -> usize { self.right }
+
+	/// Get the distance at which the clusters were merged, which is the weight of the edge that joined them.
+	pub fn distance(&self) -> &W { &self.distance }
+
+	/// Get the number of points in the new cluster.
+	pub fn size(&self) -> usize { self.size }
+}
+
+/// The merge history of single-linkage clustering, in order of increasing distance.
+///
+/// The dendrogram can be cut to get a flat partition of the points.
+/// Partitions are lists of clusters ordered by their smallest point,
+/// and the points of each cluster are in increasing order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dendrogram<W> {
+	n: usize,
+	merges: Vec<Merge<W>>,
+	/// A point from each of the clusters merged by each step.
+	links: Vec<(usize, usize)>,
+}
+
+impl<W> Dendrogram<W> {
+	/// Get the number of points.
+	pub fn len(&self) -> usize { self.n }
+
+	/// Returns `true` if there are no points.
+	pub fn is_empty(&self) -> bool { self.n == 0 }
+
+	/// Get the merge steps.
+	///
+	/// There are fewer than `len() - 1` steps if the edges do not connect every point.
+	pub fn merges(&self) -> &[Merge<W>] { &self.merges }
+
+	/// Get the clusters made by the merges at a distance of at most `threshold`.
+	///
+	/// Each cut builds a new `DisjointSet` and replays the merges,
+	/// so it takes `O(n α(n))` time for `n` points.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::clustering::single_linkage;
+	/// #
+	/// let edges = vec![(0, 1, 1), (2, 3, 2), (1, 2, 5), (3, 4, 9)];
+	/// let dendrogram = single_linkage(5, edges);
+	///
+	/// assert_eq!(vec![vec![0, 1], vec![2, 3], vec![4]], dendrogram.cut_at(&4));
+	/// assert_eq!(vec![vec![0, 1, 2, 3], vec![4]], dendrogram.cut_at(&5));
+	/// ```
+	pub fn cut_at(&self, threshold: &W) -> Vec<Vec<usize>>
+	where W: Ord {
+		let steps = self
+			.merges
+			.iter()
+			.take_while(|merge| merge.distance <= *threshold)
+			.count();
+
+		self.partition(steps)
+	}
+
+	/// Get the partition into `k` clusters, made by the merges with the smallest distances.
+	///
+	/// If the edges do not connect enough points, there are more than `k` clusters,
+	/// and if `k` is more than the number of points, every point is in its own cluster.
+	///
+	/// Like `cut_at(..)`, each cut replays the merges in a new `DisjointSet`, taking `O(n α(n))` time.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::clustering::single_linkage;
+	/// #
+	/// let edges = vec![(0, 1, 1), (2, 3, 2), (1, 2, 5), (3, 4, 9)];
+	/// let dendrogram = single_linkage(5, edges);
+	///
+	/// assert_eq!(vec![vec![0, 1, 2, 3], vec![4]], dendrogram.cut_into(2));
+	/// assert_eq!(5, dendrogram.cut_into(7).len());
+	/// ```
+	pub fn cut_into(&self, k: usize) -> Vec<Vec<usize>> {
+		self.partition(self.n.saturating_sub(k).min(self.merges.len()))
+	}
+
+	/// Replays the first `steps` merges.
+	fn partition(&self, steps: usize) -> Vec<Vec<usize>> {
+		let mut set = DisjointSet::new(self.n);
+		for &(a, b) in &self.links[..steps] {
+			set.union(a, b);
+		}
+		set.all_subsets()
+	}
+}
+
+/// Clusters the points `0..n` by single linkage, using the provided weighted edges as the distances between points.
+///
+/// The edges are processed in order of increasing weight, and every edge that joins two different clusters
+/// becomes a merge of the dendrogram. Edges with equal weights keep the order they were provided in.
+/// Pairs of points without an edge are never merged directly.
+///
+/// # Examples
+/// ```
+/// # use union_find::clustering::single_linkage;
+/// #
+/// let edges = vec![(0, 1, 1), (2, 3, 2), (1, 2, 5), (0, 3, 6)];
+/// let dendrogram = single_linkage(4, edges);
+/// let merges = dendrogram.merges();
+///
+/// assert_eq!(3, merges.len());
+/// assert_eq!((4, 5), (merges[2].left(), merges[2].right()));
+/// assert_eq!((&5, 4), (merges[2].distance(), merges[2].size()));
+/// ```
+///
+/// # Panics
+/// Panics if an endpoint of an edge is not less than `n`.
+pub fn single_linkage<W, I>(n: usize, edges: I) -> Dendrogram<W>
+where
+	W: Ord,
+	I: IntoIterator<Item = (usize, usize, W)>, {
+	let mut edges = edges.into_iter().collect::<Vec<_>>();
+	edges.sort_by(|(_, _, x), (_, _, y)| x.cmp(y));
+
+	let mut set = DisjointSet::new(n);
+	// the id of the cluster of each subset, indexed by the representative of the subset
+	let mut cluster = (0..n).collect::<Vec<_>>();
+	let mut merges = Vec::new();
+	let mut links = Vec::new();
+
+	for (a, b, distance) in edges {
+		let (root_a, root_b) = (set.find(a), set.find(b));
+		if !set.union(a, b) {
+			continue;
+		}
+
+		let (left, right) = (cluster[root_a], cluster[root_b]);
+		let root = set.find(a);
+		cluster[root] = n + merges.len();
+
+		merges.push(Merge {
+			left: left.min(right),
+			right: left.max(right),
+			distance,
+			size: set.subset_size(root),
+		});
+		links.push((a, b));
+	}
+
+	Dendrogram { n, merges, links }
+}
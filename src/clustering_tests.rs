@@ -0,0 +1,174 @@
+use crate::{clustering::single_linkage, disjoint_set::DisjointSet, kruskal::kruskal_dense};
+
+/// A xorshift generator, so the random graphs are the same on every run.
+struct XorShift(u64);
+
+impl XorShift {
+	fn next(&mut self) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0
+	}
+
+	fn below(&mut self, n: usize) -> usize { (self.next() % n as u64) as usize }
+}
+
+fn random_edges(rng: &mut XorShift, n: usize, count: usize) -> Vec<(usize, usize, u32)> {
+	(0..count)
+		.map(|_| (rng.below(n), rng.below(n), rng.below(20) as u32))
+		.collect()
+}
+
+fn merges_of(edges: Vec<(usize, usize, u32)>, n: usize) -> Vec<(usize, usize, u32, usize)> {
+	single_linkage(n, edges)
+		.merges()
+		.iter()
+		.map(|merge| (merge.left(), merge.right(), *merge.distance(), merge.size()))
+		.collect()
+}
+
+#[test]
+fn empty() {
+	let dendrogram = single_linkage(0, Vec::<(usize, usize, u32)>::new());
+	assert!(dendrogram.is_empty());
+	assert!(dendrogram.merges().is_empty());
+	assert!(dendrogram.cut_at(&0).is_empty());
+	assert!(dendrogram.cut_into(1).is_empty());
+}
+
+#[test]
+fn no_edges() {
+	let dendrogram = single_linkage(3, Vec::<(usize, usize, u32)>::new());
+	assert_eq!(3, dendrogram.len());
+	assert!(dendrogram.merges().is_empty());
+	assert_eq!(vec![vec![0], vec![1], vec![2]], dendrogram.cut_into(1));
+}
+
+#[test]
+fn merge_ids() {
+	let edges = vec![(3, 4, 1), (0, 1, 2), (1, 2, 3), (2, 3, 4)];
+	let expected = vec![(3, 4, 1, 2), (0, 1, 2, 2), (2, 6, 3, 3), (5, 7, 4, 5)];
+	assert_eq!(expected, merges_of(edges, 5));
+}
+
+#[test]
+fn redundant_edges_skipped() {
+	let edges = vec![(0, 1, 1), (1, 0, 2), (2, 2, 0), (1, 2, 3), (0, 2, 4)];
+	assert_eq!(vec![(0, 1, 1, 2), (2, 3, 3, 3)], merges_of(edges, 3));
+}
+
+#[test]
+fn ties_keep_order() {
+	let edges = vec![(2, 3, 1), (0, 1, 1), (1, 2, 1)];
+	let expected = vec![(2, 3, 1, 2), (0, 1, 1, 2), (4, 5, 1, 4)];
+	assert_eq!(expected, merges_of(edges, 4));
+}
+
+#[test]
+fn disconnected() {
+	let dendrogram = single_linkage(5, vec![(0, 1, 4), (3, 4, 2)]);
+	assert_eq!(2, dendrogram.merges().len());
+	assert_eq!(
+		vec![vec![0, 1], vec![2], vec![3, 4]],
+		dendrogram.cut_into(1)
+	);
+	assert_eq!(
+		vec![vec![0, 1], vec![2], vec![3, 4]],
+		dendrogram.cut_at(&10)
+	);
+	assert_eq!(
+		vec![vec![0], vec![1], vec![2], vec![3, 4]],
+		dendrogram.cut_at(&3)
+	);
+}
+
+#[test]
+fn cut_at_bounds() {
+	let dendrogram = single_linkage(3, vec![(0, 1, 5), (1, 2, 7)]);
+	assert_eq!(vec![vec![0], vec![1], vec![2]], dendrogram.cut_at(&4));
+	assert_eq!(vec![vec![0, 1], vec![2]], dendrogram.cut_at(&5));
+	assert_eq!(vec![vec![0, 1, 2]], dendrogram.cut_at(&7));
+}
+
+#[test]
+fn cut_into_bounds() {
+	let dendrogram = single_linkage(3, vec![(0, 1, 5), (1, 2, 7)]);
+	assert_eq!(vec![vec![0, 1, 2]], dendrogram.cut_into(0));
+	assert_eq!(vec![vec![0, 1, 2]], dendrogram.cut_into(1));
+	assert_eq!(vec![vec![0, 1], vec![2]], dendrogram.cut_into(2));
+	assert_eq!(vec![vec![0], vec![1], vec![2]], dendrogram.cut_into(3));
+	assert_eq!(vec![vec![0], vec![1], vec![2]], dendrogram.cut_into(4));
+}
+
+#[test]
+#[should_panic]
+fn endpoint_out_of_range() { single_linkage(2, vec![(0, 2, 1)]); }
+
+#[test]
+fn random_cut_at_matches_components() {
+	let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+	for _ in 0..50 {
+		let n = 1 + rng.below(30);
+		let count = rng.below(3 * n);
+		let edges = random_edges(&mut rng, n, count);
+		let dendrogram = single_linkage(n, edges.clone());
+
+		for threshold in 0..21 {
+			let mut set = DisjointSet::new(n);
+			for &(a, b, _) in edges.iter().filter(|&&(_, _, w)| w <= threshold) {
+				set.union(a, b);
+			}
+			assert_eq!(set.all_subsets(), dendrogram.cut_at(&threshold));
+		}
+	}
+}
+
+#[test]
+fn random_merges_match_spanning_forest() {
+	let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+
+	for _ in 0..50 {
+		let n = 1 + rng.below(30);
+		let count = rng.below(3 * n);
+		let edges = random_edges(&mut rng, n, count);
+		let dendrogram = single_linkage(n, edges.clone());
+
+		let mut distances = dendrogram
+			.merges()
+			.iter()
+			.map(|merge| *merge.distance())
+			.collect::<Vec<_>>();
+		let mut weights = kruskal_dense(edges)
+			.into_edges()
+			.into_iter()
+			.map(|(_, _, w)| w)
+			.collect::<Vec<_>>();
+		distances.sort_unstable();
+		weights.sort_unstable();
+		assert_eq!(weights, distances);
+
+		let mut ids = vec![false; n + dendrogram.merges().len()];
+		for (i, merge) in dendrogram.merges().iter().enumerate() {
+			assert!(merge.left() < merge.right() && merge.right() < n + i);
+			assert!(!ids[merge.left()] && !ids[merge.right()]);
+			ids[merge.left()] = true;
+			ids[merge.right()] = true;
+
+			let size = |id: usize| {
+				if id < n {
+					1
+				} else {
+					dendrogram.merges()[id - n].size()
+				}
+			};
+			assert_eq!(size(merge.left()) + size(merge.right()), merge.size());
+		}
+
+		for k in 1..=n {
+			let expected = k.max(n - dendrogram.merges().len());
+			assert_eq!(expected, dendrogram.cut_into(k).len());
+		}
+	}
+}
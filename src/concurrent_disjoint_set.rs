@@ -0,0 +1,164 @@
+use std::{
+	mem,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::unit::priority;
+
+/// A disjoint set over the elements `0..n` that can be shared between threads.
+///
+/// Every operation takes `&self`, so the set can be used through an `Arc` or a scoped borrow
+/// while many threads call `union(..)` and `find(..)` at the same time without any locking.
+///
+/// Parent pointers are atomics. A union links one root below another with a compare-and-swap,
+/// retrying if another thread changed either root first.
+/// Roots are linked by a fixed pseudo-random priority of their index,
+/// which keeps the trees shallow in expectation and guarantees that links can never form a cycle.
+/// Finding an element uses path halving, which is also done with compare-and-swap.
+///
+/// Providing an element outside of `0..len()` panics.
+#[derive(Debug)]
+pub struct ConcurrentDisjointSet {
+	parent: Vec<AtomicUsize>,
+	subset_count: AtomicUsize,
+}
+
+impl ConcurrentDisjointSet {
+	/// Creates a `ConcurrentDisjointSet` with the elements `0..n`, each in its own subset.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::concurrent_disjoint_set::ConcurrentDisjointSet;
+	/// #
+	/// let set = ConcurrentDisjointSet::new(5);
+	///
+	/// assert_eq!(5, set.subset_count());
+	/// ```
+	pub fn new(n: usize) -> Self {
+		ConcurrentDisjointSet {
+			parent: (0..n).map(AtomicUsize::new).collect(),
+			subset_count: AtomicUsize::new(n),
+		}
+	}
+
+	/// Get the number of elements in the set.
+	pub fn len(&self) -> usize { self.parent.len() }
+
+	/// Returns `true` if the set has no elements.
+	pub fn is_empty(&self) -> bool { self.parent.is_empty() }
+
+	/// Combine the subsets containing each element.
+	/// Returns `true` if this call combined two different subsets,
+	/// and `false` if the elements were already part of the same subset.
+	///
+	/// When several threads union the same pair of subsets, exactly one of them returns `true`.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::concurrent_disjoint_set::ConcurrentDisjointSet;
+	/// #
+	/// let set = ConcurrentDisjointSet::new(5);
+	///
+	/// assert!(set.union(0, 1));
+	/// assert!(!set.union(1, 0));
+	/// assert_eq!(4, set.subset_count());
+	/// ```
+	///
+	/// # Panics
+	/// Panics if either element is not less than `len()`.
+	pub fn union(&self, elem_a: usize, elem_b: usize) -> bool {
+		loop {
+			let mut root_a = self.find(elem_a);
+			let mut root_b = self.find(elem_b);
+
+			if root_a == root_b {
+				return false;
+			}
+
+			if priority(root_a) < priority(root_b) {
+				mem::swap(&mut root_a, &mut root_b);
+			}
+
+			if self.parent[root_b]
+				.compare_exchange(root_b, root_a, Ordering::AcqRel, Ordering::Acquire)
+				.is_ok()
+			{
+				self.subset_count.fetch_sub(1, Ordering::AcqRel);
+				return true;
+			}
+		}
+	}
+
+	/// Identify the subset of an element by returning the element that represents it.
+	///
+	/// While other threads are performing unions, the returned element may stop being
+	/// the representative of the subset at any moment.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::concurrent_disjoint_set::ConcurrentDisjointSet;
+	/// #
+	/// let set = ConcurrentDisjointSet::new(5);
+	/// set.union(3, 4);
+	///
+	/// assert_eq!(set.find(3), set.find(4));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn find(&self, elem: usize) -> usize {
+		let mut elem = elem;
+		loop {
+			let parent = self.parent[elem].load(Ordering::Acquire);
+			if parent == elem {
+				return elem;
+			}
+
+			let grandparent = self.parent[parent].load(Ordering::Acquire);
+			if parent != grandparent {
+				// another thread may have moved `elem` already, in which case it is left alone
+				let _ = self.parent[elem].compare_exchange_weak(
+					parent,
+					grandparent,
+					Ordering::AcqRel,
+					Ordering::Acquire,
+				);
+			}
+			elem = grandparent;
+		}
+	}
+
+	/// Determine if two elements are in the same subset.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::concurrent_disjoint_set::ConcurrentDisjointSet;
+	/// #
+	/// let set = ConcurrentDisjointSet::new(5);
+	/// set.union(3, 4);
+	///
+	/// assert!(set.same_subset(4, 3));
+	/// assert!(!set.same_subset(0, 3));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if either element is not less than `len()`.
+	pub fn same_subset(&self, elem_a: usize, elem_b: usize) -> bool {
+		loop {
+			let root_a = self.find(elem_a);
+			let root_b = self.find(elem_b);
+
+			if root_a == root_b {
+				return true;
+			}
+
+			// the answer is only certain if `root_a` was not linked while finding `root_b`
+			if self.parent[root_a].load(Ordering::Acquire) == root_a {
+				return false;
+			}
+		}
+	}
+
+	/// Get the number of disjoint subsets in the set.
+	pub fn subset_count(&self) -> usize { self.subset_count.load(Ordering::Acquire) }
+}
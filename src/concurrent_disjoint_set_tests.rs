@@ -0,0 +1,132 @@
+use std::{
+	sync::atomic::{AtomicUsize, Ordering},
+	thread,
+};
+
+use crate::{concurrent_disjoint_set::ConcurrentDisjointSet, disjoint_set::DisjointSet};
+
+const THREADS: usize = 8;
+
+/// A deterministic list of edges over `0..n`, spread so that threads contend on the same subsets.
+fn edges(n: usize, count: usize) -> Vec<(usize, usize)> {
+	let mut state = 0x2545_f491_4f6c_dd1d_u64;
+	let mut next = || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state as usize % n
+	};
+	(0..count).map(|_| (next(), next())).collect()
+}
+
+#[test]
+fn create_new() {
+	let set = ConcurrentDisjointSet::new(10);
+	assert_eq!(10, set.len());
+	assert_eq!(10, set.subset_count());
+	assert!(!set.is_empty());
+	assert!(ConcurrentDisjointSet::new(0).is_empty());
+}
+
+#[test]
+fn union_subset_count() {
+	let set = ConcurrentDisjointSet::new(10);
+	assert!(set.union(1, 2));
+	assert!(set.union(0, 2));
+	assert!(!set.union(1, 0));
+	assert!(!set.union(4, 4));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn find_same_subset() {
+	let set = ConcurrentDisjointSet::new(6);
+	set.union(5, 1);
+	set.union(3, 5);
+
+	assert_eq!(set.find(1), set.find(3));
+	assert!(set.same_subset(3, 1));
+	assert!(!set.same_subset(0, 1));
+}
+
+#[test]
+#[should_panic]
+fn find_out_of_bounds() { ConcurrentDisjointSet::new(3).find(3); }
+
+#[test]
+fn chain_under_contention() {
+	let n = 10_000;
+	let set = ConcurrentDisjointSet::new(n);
+
+	thread::scope(|scope| {
+		for _ in 0..THREADS {
+			scope.spawn(|| {
+				for i in 0..n - 1 {
+					set.union(i, i + 1);
+				}
+			});
+		}
+	});
+
+	assert_eq!(1, set.subset_count());
+	let root = set.find(0);
+	assert!((0..n).all(|i| set.find(i) == root));
+}
+
+#[test]
+fn one_winner_per_merge() {
+	let n = 2_000;
+	let set = ConcurrentDisjointSet::new(n);
+	let merges = AtomicUsize::new(0);
+
+	thread::scope(|scope| {
+		for t in 0..THREADS {
+			let set = &set;
+			let merges = &merges;
+			scope.spawn(move || {
+				(0..n)
+					.map(|i| (i + t * 37) % n)
+					.filter(|&i| set.union(i, (i * 7 + 3) % n))
+					.for_each(|_| {
+						merges.fetch_add(1, Ordering::SeqCst);
+					});
+			});
+		}
+	});
+
+	assert_eq!(n - set.subset_count(), merges.load(Ordering::SeqCst));
+}
+
+#[test]
+fn matches_sequential() {
+	let n = 5_000;
+	let edges = edges(n, 4_000);
+	let set = ConcurrentDisjointSet::new(n);
+
+	thread::scope(|scope| {
+		for chunk in edges.chunks(edges.len() / THREADS) {
+			let set = &set;
+			scope.spawn(move || {
+				for &(a, b) in chunk {
+					set.union(a, b);
+				}
+			});
+		}
+		scope.spawn(|| {
+			for &(a, b) in &edges {
+				set.same_subset(a, b);
+			}
+		});
+	});
+
+	let mut expected = DisjointSet::new(n);
+	for &(a, b) in &edges {
+		expected.union(a, b);
+	}
+
+	assert_eq!(expected.subset_count(), set.subset_count());
+	for i in 0..n {
+		let j = (i * 31 + 17) % n;
+		assert_eq!(expected.same_subset(i, j), set.same_subset(i, j));
+	}
+}
@@ -0,0 +1,286 @@
+use std::{collections::HashMap, ops::Range};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::unit::{self, Unit};
+
+/// A disjoint set over the elements `0..n` that uses a `Vec` without any hashing.
+///
+/// This is intended for elements that are already densely numbered,
+/// such as the vertices of a graph.
+/// Elements are plain indices, so providing an element outside of `0..len()` panics
+/// in the same way as indexing a `Vec` out of bounds.
+///
+/// Path splitting is used. The union operation is done by size.
+///
+/// With the `serde` feature, a `DisjointSet` is serialized as a list
+/// holding the representative of each element.
+#[derive(Default)]
+pub struct DisjointSet {
+	set: Vec<Unit>,
+	subset_count: usize,
+}
+
+impl DisjointSet {
+	/// Creates a `DisjointSet` with the elements `0..n`, each in its own subset.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let set = DisjointSet::new(5);
+	///
+	/// assert_eq!(5, set.len());
+	/// assert_eq!(5, set.subset_count());
+	/// ```
+	pub fn new(n: usize) -> Self {
+		DisjointSet {
+			set: (0..n).map(Unit::new).collect(),
+			subset_count: n,
+		}
+	}
+
+	/// Adds `k` new elements, each in its own subset.
+	/// The range of the added elements is returned.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(5);
+	/// let added = set.grow(3);
+	///
+	/// assert_eq!(5..8, added);
+	/// assert_eq!(8, set.subset_count());
+	/// ```
+	pub fn grow(&mut self, k: usize) -> Range<usize> {
+		let start = self.set.len();
+		let end = start + k;
+
+		self.set.extend((start..end).map(Unit::new));
+		self.subset_count += k;
+
+		start..end
+	}
+
+	/// Get the number of elements in the set.
+	pub fn len(&self) -> usize { self.set.len() }
+
+	/// Returns `true` if the set has no elements.
+	pub fn is_empty(&self) -> bool { self.set.is_empty() }
+
+	/// Combine the subsets containing each element.
+	/// Returns `true` if the elements were in different subsets,
+	/// and `false` if they were already part of the same subset, in which case no change occurs.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(5);
+	///
+	/// assert!(set.union(0, 1));
+	/// assert!(!set.union(1, 0));
+	/// assert_eq!(4, set.subset_count());
+	/// ```
+	///
+	/// # Panics
+	/// Panics if either element is not less than `len()`.
+	pub fn union(&mut self, elem_a: usize, elem_b: usize) -> bool {
+		let merged = unit::union(&mut self.set, elem_a, elem_b).is_some();
+		if merged {
+			self.subset_count -= 1;
+		}
+		merged
+	}
+
+	/// Identify the subset of an element by returning the element that represents it.
+	///
+	/// The representative of a subset may change when the subset is combined with another.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(5);
+	/// set.union(3, 4);
+	///
+	/// assert_eq!(set.find(3), set.find(4));
+	/// assert_ne!(set.find(3), set.find(2));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn find(&mut self, elem: usize) -> usize { unit::find(&mut self.set, elem) }
+
+	/// Identify the subset of an element without modifying the set.
+	///
+	/// Paths are not compressed, so this can be used through a shared reference.
+	/// The result is the same element `find(..)` would return.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(5);
+	/// set.union(3, 4);
+	/// let set = &set;
+	///
+	/// assert_eq!(set.find_readonly(3), set.find_readonly(4));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn find_readonly(&self, elem: usize) -> usize { unit::root(&self.set, elem) }
+
+	/// The parent of an element in the forest, so tests can observe how paths are compressed.
+	#[cfg(test)]
+	pub(crate) fn parent(&self, elem: usize) -> usize { self.set[elem].parent }
+
+	/// Determine if two elements are in the same subset.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(5);
+	/// set.union(3, 4);
+	///
+	/// assert!(set.same_subset(4, 3));
+	/// assert!(!set.same_subset(0, 3));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if either element is not less than `len()`.
+	pub fn same_subset(&mut self, elem_a: usize, elem_b: usize) -> bool {
+		self.find(elem_a) == self.find(elem_b)
+	}
+
+	/// Determine if two elements are in the same subset without modifying the set.
+	///
+	/// # Panics
+	/// Panics if either element is not less than `len()`.
+	pub fn same_subset_readonly(&self, elem_a: usize, elem_b: usize) -> bool {
+		self.find_readonly(elem_a) == self.find_readonly(elem_b)
+	}
+
+	/// Get the number of disjoint subsets in the set.
+	pub fn subset_count(&self) -> usize { self.subset_count }
+
+	/// Get the number of elements in the subset containing the provided element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(5);
+	/// set.union(3, 4);
+	///
+	/// assert_eq!(2, set.subset_size(3));
+	/// assert_eq!(1, set.subset_size(0));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn subset_size(&mut self, elem: usize) -> usize {
+		let root = self.find(elem);
+		self.set[root].size
+	}
+
+	/// Get the number of elements in the subset containing the provided element without modifying the set.
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn subset_size_readonly(&self, elem: usize) -> usize {
+		self.set[self.find_readonly(elem)].size
+	}
+
+	/// Get all the elements in the same subset as the provided element, in increasing order.
+	/// The provided element is included.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(5);
+	/// set.union(3, 1);
+	///
+	/// assert_eq!(vec![1, 3], set.subset_containing(3));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn subset_containing(&mut self, elem: usize) -> Vec<usize> {
+		let root = self.find(elem);
+		let mut subset = Vec::with_capacity(self.set[root].size);
+
+		for i in 0..self.set.len() {
+			if unit::find(&mut self.set, i) == root {
+				subset.push(i);
+			}
+		}
+
+		subset
+	}
+
+	/// Get a list of all the subsets in the disjoint set.
+	///
+	/// Subsets are ordered by their smallest element, and the elements of each subset are in increasing order.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::disjoint_set::DisjointSet;
+	/// #
+	/// let mut set = DisjointSet::new(4);
+	/// set.union(3, 1);
+	///
+	/// assert_eq!(vec![vec![0], vec![1, 3], vec![2]], set.all_subsets());
+	/// ```
+	pub fn all_subsets(&mut self) -> Vec<Vec<usize>> {
+		let mut subset_map = HashMap::with_capacity(self.subset_count);
+		let mut subsets: Vec<Vec<usize>> = Vec::with_capacity(self.subset_count);
+
+		for i in 0..self.set.len() {
+			let root = unit::find(&mut self.set, i);
+			let size = self.set[root].size;
+			let entry = *subset_map.entry(root).or_insert_with(|| {
+				subsets.push(Vec::with_capacity(size));
+				subsets.len() - 1
+			});
+			subsets[entry].push(i);
+		}
+
+		subsets
+	}
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DisjointSet {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		serializer.collect_seq((0..self.set.len()).map(|i| unit::root(&self.set, i)))
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DisjointSet {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		let roots = Vec::<usize>::deserialize(deserializer)?;
+		let mut set = DisjointSet::new(roots.len());
+
+		for (i, &root) in roots.iter().enumerate() {
+			if root >= roots.len() {
+				return Err(de::Error::custom(format!(
+					"representative {} is not an element of a set of length {}",
+					root,
+					roots.len()
+				)));
+			}
+			set.union(i, root);
+		}
+
+		Ok(set)
+	}
+}
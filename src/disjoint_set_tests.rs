@@ -0,0 +1,146 @@
+use crate::disjoint_set::DisjointSet;
+
+#[test]
+fn create_new() {
+	let set = DisjointSet::new(10);
+	assert_eq!(10, set.len());
+	assert_eq!(10, set.subset_count());
+}
+
+#[test]
+fn create_default() {
+	let set = DisjointSet::default();
+	assert!(set.is_empty());
+	assert_eq!(0, set.subset_count());
+}
+
+#[test]
+fn grow_subset_count() {
+	let mut set = DisjointSet::new(3);
+	set.union(0, 1);
+	assert_eq!(3..5, set.grow(2));
+	assert_eq!(5, set.len());
+	assert_eq!(4, set.subset_count());
+}
+
+#[test]
+fn grow_default() {
+	let mut set = DisjointSet::default();
+	assert_eq!(0..4, set.grow(4));
+	assert!(set.union(0, 3));
+	assert!(set.same_subset(3, 0));
+}
+
+#[test]
+fn union_change_subset_count() {
+	let mut set = DisjointSet::new(10);
+	assert!(set.union(1, 2));
+	assert!(set.union(0, 2));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn union_no_change_subset_count() {
+	let mut set = DisjointSet::new(10);
+	set.union(1, 2);
+	set.union(0, 2);
+	assert!(!set.union(1, 0));
+	assert!(!set.union(4, 4));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn union_subset_size() {
+	let mut set = DisjointSet::new(10);
+	set.union(1, 2);
+	set.union(0, 2);
+	set.union(5, 6);
+	set.union(6, 0);
+	assert_eq!(5, set.subset_size(1));
+	assert_eq!(1, set.subset_size(9));
+}
+
+#[test]
+#[should_panic]
+fn union_out_of_bounds() { DisjointSet::new(3).union(0, 3); }
+
+#[test]
+#[should_panic]
+fn find_out_of_bounds() { DisjointSet::new(3).find(3); }
+
+#[test]
+fn simple_find() {
+	let mut set = DisjointSet::new(3);
+	assert_eq!(2, set.find(2));
+	assert_ne!(set.find(0), set.find(1));
+}
+
+#[test]
+fn path_compression_find() {
+	let mut set = DisjointSet::new(8);
+	set.union(0, 1);
+	set.union(2, 3);
+	set.union(0, 2);
+	set.union(4, 5);
+	set.union(6, 7);
+	set.union(4, 6);
+	set.union(0, 4);
+	assert_eq!((6, 4), (set.parent(7), set.parent(6)));
+
+	let root = set.find(7);
+	assert_eq!(0, root);
+	assert_eq!((4, 0), (set.parent(7), set.parent(6)));
+
+	set.find(7);
+	assert_eq!(root, set.parent(7));
+	assert!((0..8).all(|i| set.find(i) == root));
+}
+
+#[test]
+fn same_subset() {
+	let mut set = DisjointSet::new(4);
+	set.union(0, 3);
+	assert!(set.same_subset(3, 0));
+	assert!(set.same_subset(1, 1));
+	assert!(!set.same_subset(1, 3));
+}
+
+#[test]
+fn subset_containing() {
+	let mut set = DisjointSet::new(6);
+	set.union(5, 1);
+	set.union(3, 5);
+	assert_eq!(vec![1, 3, 5], set.subset_containing(1));
+	assert_eq!(vec![2], set.subset_containing(2));
+}
+
+#[test]
+fn all_subsets() {
+	let mut set = DisjointSet::new(6);
+	set.union(5, 1);
+	set.union(3, 5);
+	set.union(4, 0);
+	assert_eq!(vec![vec![0, 4], vec![1, 3, 5], vec![2]], set.all_subsets());
+}
+
+#[test]
+fn default_all_subsets() {
+	assert_eq!(
+		Vec::<Vec<usize>>::new(),
+		DisjointSet::default().all_subsets()
+	);
+}
+
+#[test]
+fn readonly_queries() {
+	let mut set = DisjointSet::new(6);
+	set.union(5, 1);
+	set.union(3, 5);
+	let shared = &set;
+
+	assert_eq!(shared.find_readonly(1), shared.find_readonly(3));
+	assert!(shared.same_subset_readonly(5, 3));
+	assert!(!shared.same_subset_readonly(0, 3));
+	assert_eq!(3, shared.subset_size_readonly(1));
+	assert_eq!(set.find_readonly(5), set.find(5));
+}
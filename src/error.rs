@@ -1,7 +1,7 @@
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{Debug, Display, Formatter, Result};
 
-/// Error type used by `hash_disjoint_set`
-#[derive(Debug, PartialEq)]
+/// Error type used by `hash_disjoint_set` and the other disjoint sets keyed by hashable elements
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HashDisjointSetError {
 	/// returned when a method tries to look for an element that does not exist in the set (any of the disjoint subsets).
 	ElementNotDefined,
@@ -25,3 +25,131 @@ impl Display for HashDisjointSetError {
 }
 
 impl std::error::Error for HashDisjointSetError {}
+
+/// Error type used when recording a relation between two elements of a weighted or parity set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelationError {
+	/// returned when one of the elements could not be used, see `HashDisjointSetError`.
+	Element(HashDisjointSetError),
+	/// returned when the relation between the two elements conflicts with the relations already in the set.
+	Contradiction,
+}
+
+impl Display for RelationError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		match self {
+			RelationError::Element(error) => write!(f, "{}", error),
+			RelationError::Contradiction => {
+				write!(
+					f,
+					"The relation contradicts the relations already in this set."
+				)
+			},
+		}
+	}
+}
+
+impl std::error::Error for RelationError {}
+
+impl From<HashDisjointSetError> for RelationError {
+	fn from(error: HashDisjointSetError) -> Self { RelationError::Element(error) }
+}
+
+/// Error returned when rolling back to or committing a checkpoint that was not taken from the set,
+/// or that was discarded by rolling back to or committing an earlier checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCheckpoint;
+
+impl Display for InvalidCheckpoint {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		write!(f, "The checkpoint is not valid for this set.")
+	}
+}
+
+impl std::error::Error for InvalidCheckpoint {}
+
+/// Error returned when a `SubsetTicket` was taken from a different set,
+/// or from a version of the set that can no longer be resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaleTicket;
+
+impl Display for StaleTicket {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		write!(f, "The ticket does not identify a subset of this set.")
+	}
+}
+
+impl std::error::Error for StaleTicket {}
+
+/// The argument of an operation that an `ElementError` refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Argument {
+	/// the first (or only) element argument.
+	First,
+	/// the second element argument.
+	Second,
+}
+
+impl Display for Argument {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		write!(
+			f,
+			"{}",
+			match self {
+				Argument::First => "first",
+				Argument::Second => "second",
+			}
+		)
+	}
+}
+
+/// A `HashDisjointSetError` together with the element that caused it and the argument it was passed as.
+///
+/// This is returned by the `try_..` methods of the hashed disjoint sets,
+/// and can be converted into the plain `HashDisjointSetError` with `?` or `into()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementError<E> {
+	kind: HashDisjointSetError,
+	element: E,
+	argument: Argument,
+}
+
+impl<E> ElementError<E> {
+	pub(crate) fn new(kind: HashDisjointSetError, element: E, argument: Argument) -> Self {
+		ElementError {
+			kind,
+			element,
+			argument,
+		}
+	}
+
+	/// Get the kind of error.
+	pub fn kind(&self) -> &HashDisjointSetError { &self.kind }
+
+	/// Get the element that caused the error.
+	pub fn element(&self) -> &E { &self.element }
+
+	/// Take the element that caused the error.
+	pub fn into_element(self) -> E { self.element }
+
+	/// Get the argument that the element was passed as.
+	pub fn argument(&self) -> Argument { self.argument }
+}
+
+impl<E> Display for ElementError<E>
+where E: Debug
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		write!(
+			f,
+			"{} (element {:?}, {} argument)",
+			self.kind, self.element, self.argument
+		)
+	}
+}
+
+impl<E> std::error::Error for ElementError<E> where E: Debug {}
+
+impl<E> From<ElementError<E>> for HashDisjointSetError {
+	fn from(error: ElementError<E>) -> Self { error.kind }
+}
@@ -0,0 +1,211 @@
+use crate::disjoint_set::DisjointSet;
+
+/// The cells that are considered neighbors of a cell in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+	/// The cells sharing an edge with a cell.
+	Four,
+	/// The cells sharing an edge or a corner with a cell.
+	Eight,
+}
+
+impl Connectivity {
+	/// The offsets of the neighbors that come before a cell in row-major order.
+	fn previous_neighbors(self) -> &'static [(isize, isize)] {
+		match self {
+			Connectivity::Four => &[(-1, 0), (0, -1)],
+			Connectivity::Eight => &[(-1, 0), (-1, -1), (0, -1), (1, -1)],
+		}
+	}
+}
+
+/// The connected components of a grid, as a label for each cell.
+///
+/// Components are numbered from `0` in the row-major order of their first cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridLabels {
+	width: usize,
+	height: usize,
+	labels: Vec<Option<usize>>,
+	sizes: Vec<usize>,
+}
+
+impl GridLabels {
+	/// Get the width of the grid.
+	pub fn width(&self) -> usize { self.width }
+
+	/// Get the height of the grid.
+	pub fn height(&self) -> usize { self.height }
+
+	/// Get the label of the component containing the cell at column `x` and row `y`,
+	/// or `None` if the cell is not part of any component.
+	///
+	/// # Panics
+	/// Panics if the cell is outside of the grid.
+	pub fn label(&self, x: usize, y: usize) -> Option<usize> {
+		assert!(
+			x < self.width && y < self.height,
+			"the cell ({}, {}) is outside of the {}x{} grid",
+			x,
+			y,
+			self.width,
+			self.height
+		);
+		self.labels[y * self.width + x]
+	}
+
+	/// Get the label of every cell in row-major order.
+	pub fn labels(&self) -> &[Option<usize>] { &self.labels }
+
+	/// Get the number of cells in each component, indexed by label.
+	pub fn sizes(&self) -> &[usize] { &self.sizes }
+
+	/// Get the number of components.
+	pub fn component_count(&self) -> usize { self.sizes.len() }
+}
+
+/// Labels the connected regions of foreground cells in a `width` by `height` grid, such as a binary image.
+///
+/// `is_foreground` is called once for each cell with its column and row.
+/// Background cells have no label.
+///
+/// # Examples
+/// ```
+/// # use union_find::grid_labeling::{label_foreground, Connectivity};
+/// #
+/// let image = ["#..#", ".#.#", "...."];
+/// let is_set = |x: usize, y: usize| image[y].as_bytes()[x] == b'#';
+///
+/// let four = label_foreground(4, 3, Connectivity::Four, is_set);
+/// assert_eq!(&[1, 2, 1], four.sizes());
+///
+/// let eight = label_foreground(4, 3, Connectivity::Eight, is_set);
+/// assert_eq!(2, eight.component_count());
+/// assert_eq!(None, eight.label(2, 0));
+/// assert_eq!(eight.label(0, 0), eight.label(1, 1));
+/// ```
+pub fn label_foreground<F>(
+	width: usize,
+	height: usize,
+	connectivity: Connectivity,
+	is_foreground: F,
+) -> GridLabels
+where
+	F: FnMut(usize, usize) -> bool,
+{
+	label(width, height, connectivity, is_foreground, |_, _| true)
+}
+
+/// Labels the connected regions of a `width` by `height` grid, where neighboring cells are in the same region
+/// if `same_region` returns `true` for their positions, such as when two pixels have the same color.
+///
+/// Every cell has a label. `same_region` is called with the column and row of two neighboring cells,
+/// and should be symmetric.
+///
+/// # Examples
+/// ```
+/// # use union_find::grid_labeling::{label_regions, Connectivity};
+/// #
+/// let image = ["aab", "cab", "ccb"];
+/// let pixel = |(x, y): (usize, usize)| image[y].as_bytes()[x];
+///
+/// let labels = label_regions(3, 3, Connectivity::Four, |a, b| pixel(a) == pixel(b));
+///
+/// assert_eq!(3, labels.component_count());
+/// assert_eq!(&[3, 3, 3], labels.sizes());
+/// assert_eq!(labels.label(0, 0), labels.label(1, 1));
+/// ```
+pub fn label_regions<F>(
+	width: usize,
+	height: usize,
+	connectivity: Connectivity,
+	same_region: F,
+) -> GridLabels
+where
+	F: FnMut((usize, usize), (usize, usize)) -> bool,
+{
+	label(width, height, connectivity, |_, _| true, same_region)
+}
+
+/// Labels the grid in two passes.
+///
+/// The first pass gives each included cell the provisional label of a connected neighbor that comes before it,
+/// or a new provisional label, and records that the labels of all such neighbors are equivalent in a `DisjointSet`.
+/// The second pass replaces each provisional label with a final label for its subset.
+fn label<I, C>(
+	width: usize,
+	height: usize,
+	connectivity: Connectivity,
+	mut include: I,
+	mut connected: C,
+) -> GridLabels
+where
+	I: FnMut(usize, usize) -> bool,
+	C: FnMut((usize, usize), (usize, usize)) -> bool,
+{
+	let mut provisional = vec![None; width * height];
+	let mut equivalent = DisjointSet::default();
+
+	for y in 0..height {
+		for x in 0..width {
+			if !include(x, y) {
+				continue;
+			}
+
+			let mut label = None;
+			for &(dx, dy) in connectivity.previous_neighbors() {
+				let neighbor = match (offset(x, dx, width), offset(y, dy, height)) {
+					(Some(nx), Some(ny)) => (nx, ny),
+					_ => continue,
+				};
+
+				if let Some(neighbor_label) = provisional[neighbor.1 * width + neighbor.0] {
+					if connected(neighbor, (x, y)) {
+						match label {
+							Some(label) => {
+								equivalent.union(label, neighbor_label);
+							},
+							None => label = Some(neighbor_label),
+						}
+					}
+				}
+			}
+
+			provisional[y * width + x] = Some(label.unwrap_or_else(|| equivalent.grow(1).start));
+		}
+	}
+
+	let mut final_labels = vec![None; equivalent.len()];
+	let mut sizes = Vec::new();
+	let labels = provisional
+		.into_iter()
+		.map(|label| {
+			label.map(|label| {
+				let root = equivalent.find(label);
+				let label = *final_labels[root].get_or_insert_with(|| {
+					sizes.push(0);
+					sizes.len() - 1
+				});
+				sizes[label] += 1;
+				label
+			})
+		})
+		.collect();
+
+	GridLabels {
+		width,
+		height,
+		labels,
+		sizes,
+	}
+}
+
+/// Moves `coordinate` by `delta`, if the result is within `0..len`.
+fn offset(coordinate: usize, delta: isize, len: usize) -> Option<usize> {
+	let moved = coordinate as isize + delta;
+	if moved >= 0 && (moved as usize) < len {
+		Some(moved as usize)
+	} else {
+		None
+	}
+}
@@ -0,0 +1,143 @@
+use crate::grid_labeling::{label_foreground, label_regions, Connectivity, GridLabels};
+
+fn parse(rows: &[&str]) -> (usize, usize, Vec<Vec<u8>>) {
+	let cells = rows.iter().map(|row| row.bytes().collect()).collect();
+	(rows[0].len(), rows.len(), cells)
+}
+
+/// Labels the foreground with a flood fill, numbering components in row-major order of their first cell.
+fn flood_fill(rows: &[&str], connectivity: Connectivity) -> Vec<Option<usize>> {
+	let (width, height, cells) = parse(rows);
+	let mut labels = vec![None; width * height];
+	let mut next = 0;
+
+	for y in 0..height {
+		for x in 0..width {
+			if cells[y][x] != b'#' || labels[y * width + x].is_some() {
+				continue;
+			}
+
+			let mut stack = vec![(x, y)];
+			labels[y * width + x] = Some(next);
+			while let Some((cx, cy)) = stack.pop() {
+				for dy in -1isize..=1 {
+					for dx in -1isize..=1 {
+						if (dx == 0 && dy == 0)
+							|| (connectivity == Connectivity::Four && dx != 0 && dy != 0)
+						{
+							continue;
+						}
+						let (nx, ny) = (cx as isize + dx, cy as isize + dy);
+						if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+							continue;
+						}
+						let (nx, ny) = (nx as usize, ny as usize);
+						if cells[ny][nx] == b'#' && labels[ny * width + nx].is_none() {
+							labels[ny * width + nx] = Some(next);
+							stack.push((nx, ny));
+						}
+					}
+				}
+			}
+			next += 1;
+		}
+	}
+
+	labels
+}
+
+fn label_image(rows: &[&str], connectivity: Connectivity) -> GridLabels {
+	let (width, height, cells) = parse(rows);
+	label_foreground(width, height, connectivity, |x, y| cells[y][x] == b'#')
+}
+
+#[test]
+fn empty_grid() {
+	let labels = label_foreground(0, 0, Connectivity::Eight, |_, _| true);
+	assert_eq!(0, labels.component_count());
+	assert!(labels.labels().is_empty());
+}
+
+#[test]
+fn all_background() {
+	let labels = label_foreground(3, 2, Connectivity::Four, |_, _| false);
+	assert_eq!(0, labels.component_count());
+	assert_eq!(&[None; 6], labels.labels());
+}
+
+#[test]
+fn all_foreground() {
+	let labels = label_foreground(5, 4, Connectivity::Four, |_, _| true);
+	assert_eq!(&[20], labels.sizes());
+	assert_eq!((5, 4), (labels.width(), labels.height()));
+}
+
+#[test]
+fn u_shape_merges_provisional_labels() {
+	let rows = ["#.#.#", "#.#.#", "#####"];
+	let labels = label_image(&rows, Connectivity::Four);
+	assert_eq!(&[11], labels.sizes());
+	assert_eq!(Some(0), labels.label(4, 0));
+}
+
+#[test]
+fn diagonal_connectivity() {
+	let rows = ["#...#", ".#.#.", "..#..", ".#.#.", "#...#"];
+	assert_eq!(9, label_image(&rows, Connectivity::Four).component_count());
+	assert_eq!(&[9], label_image(&rows, Connectivity::Eight).sizes());
+}
+
+#[test]
+fn anti_diagonal_needs_upper_right_neighbor() {
+	let rows = ["..#", ".#.", "#.."];
+	assert_eq!(3, label_image(&rows, Connectivity::Four).component_count());
+	assert_eq!(1, label_image(&rows, Connectivity::Eight).component_count());
+}
+
+#[test]
+fn matches_flood_fill() {
+	let rows = [
+		"##..#..###.#",
+		"#..##.#...#.",
+		"..#...#.##..",
+		"###.#.#..#.#",
+		"#...##...#.#",
+		".#.#..##.###",
+		"##..#.#.....",
+	];
+
+	for &connectivity in &[Connectivity::Four, Connectivity::Eight] {
+		let labels = label_image(&rows, connectivity);
+		let expected = flood_fill(&rows, connectivity);
+		assert_eq!(expected.as_slice(), labels.labels());
+
+		let mut sizes = vec![0; labels.component_count()];
+		expected
+			.iter()
+			.flatten()
+			.for_each(|&label| sizes[label] += 1);
+		assert_eq!(sizes.as_slice(), labels.sizes());
+	}
+}
+
+#[test]
+fn regions_by_value() {
+	let (width, height, cells) = parse(&["aabc", "abca", "ccaa"]);
+	let pixel = |(x, y): (usize, usize)| cells[y][x];
+
+	let four = label_regions(width, height, Connectivity::Four, |a, b| {
+		pixel(a) == pixel(b)
+	});
+	assert_eq!(&[3, 1, 1, 1, 1, 3, 2], four.sizes());
+	assert!(four.labels().iter().all(Option::is_some));
+
+	let eight = label_regions(width, height, Connectivity::Eight, |a, b| {
+		pixel(a) == pixel(b)
+	});
+	assert_eq!(&[3, 2, 4, 3], eight.sizes());
+	assert_eq!(eight.label(3, 0), eight.label(0, 2));
+}
+
+#[test]
+#[should_panic]
+fn label_outside_grid() { label_foreground(2, 2, Connectivity::Four, |_, _| true).label(2, 0); }
@@ -4,38 +4,68 @@ use std::{
 	fmt::Debug,
 	hash,
 	iter,
-	marker::PhantomData,
-	mem,
 	result,
-	sync::atomic::{AtomicUsize, Ordering},
 };
 
-pub use crate::error::HashDisjointSetError;
-use crate::{SubsetTicket, UnionFind};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
 
-type Result<T> = result::Result<T, HashDisjointSetError>;
+pub use crate::{
+	error::{Argument, ElementError, HashDisjointSetError, StaleTicket},
+	subsets::Members,
+};
+use crate::{
+	strategy::{BySize, Compress, Link, PathSplitting},
+	subsets::Subsets,
+	ReadonlyUnionFind,
+	StableTicket,
+	SubsetTicket,
+	UnionFind,
+};
 
-static SET_ID: AtomicUsize = AtomicUsize::new(0);
+type Result<T> = result::Result<T, HashDisjointSetError>;
+/// The result of a `try_..` method, whose error borrows the element that caused it.
+type Attempt<'e, R, E> = result::Result<R, ElementError<&'e E>>;
 
 /// Uses a `HashMap` and `Vec` to do meet the requirements for the `UnionFind` trait.
 ///
 /// Path splitting is used. The union operation is done by size.
-pub struct HashDisjointSet<'a, T>
-where T: hash::Hash + Eq {
-	ver: usize,
-	map: HashMap<&'a T, usize>,
-	set: Vec<Unit>,
-	subset_count: usize,
-	set_id: usize,
-}
+/// A set using other strategies is a `StrategyHashDisjointSet`.
+///
+/// With the `serde` feature, a `HashDisjointSet` is serialized as a list of its subsets.
+/// Since the set borrows its elements, it cannot be deserialized directly,
+/// but the same data can be deserialized into an `OwnedHashDisjointSet`.
+pub type HashDisjointSet<'a, T> = StrategyHashDisjointSet<'a, T, BySize, PathSplitting>;
 
-struct Unit {
-	size: usize,
-	parent: usize,
+/// A `HashDisjointSet` whose union and find operations use the strategies `L` and `C`
+/// from the `strategy` module.
+///
+/// # Examples
+/// ```
+/// # use union_find::hash_disjoint_set::{HashDisjointSet, StrategyHashDisjointSet};
+/// # use union_find::strategy::{ByRank, PathHalving};
+/// # use std::iter::FromIterator;
+/// # use union_find::UnionFind;
+/// #
+/// let set = HashDisjointSet::from_iter(b"This is a test.");
+/// let ranked = StrategyHashDisjointSet::<_, ByRank, PathHalving>::from_iter(b"This is a test.");
+///
+/// assert_eq!(set.subset_count(), ranked.subset_count());
+/// ```
+pub struct StrategyHashDisjointSet<'a, T, L, C>
+where
+	T: hash::Hash + Eq,
+	L: Link, {
+	map: HashMap<&'a T, usize>,
+	elems: Vec<&'a T>,
+	subsets: Subsets<L, C>,
 }
 
-impl<'a, T: 'a> UnionFind<'a, T> for HashDisjointSet<'a, T>
-where T: hash::Hash + Eq
+impl<'a, T: 'a, L, C> UnionFind<'a, T> for StrategyHashDisjointSet<'a, T, L, C>
+where
+	T: hash::Hash + Eq,
+	L: Link,
+	C: Compress,
 {
 	type UnionFindError = HashDisjointSetError;
 
@@ -43,139 +73,174 @@ where T: hash::Hash + Eq
 		let a_i = self.index(elem_a)?;
 		let b_i = self.index(elem_b)?;
 
-		let mut root_a = Self::find_internal(&mut self.set, a_i);
-		let mut root_b = Self::find_internal(&mut self.set, b_i);
-
-		if root_a != root_b {
-			if self.set[root_a].size < self.set[root_b].size {
-				mem::swap(&mut root_a, &mut root_b);
-			}
-
-			self.set[root_b].parent = root_a;
-			self.set[root_a].size += self.set[root_b].size;
-
-			self.subset_count -= 1;
-			self.ver += 1;
-		}
+		self.subsets.union(a_i, b_i);
 
 		Ok(())
 	}
 
 	fn find(&mut self, elem: &T) -> Result<SubsetTicket<T>> {
 		let i = self.index(elem)?;
-		let root = Self::find_internal(&mut self.set, i);
+		let root = self.subsets.find(i);
 
-		Ok(SubsetTicket {
-			ver: self.ver,
-			id: root,
-			set_id: self.set_id,
-			phantom: PhantomData,
-		})
+		Ok(self.subsets.ticket(root))
 	}
 
 	fn subset_containing(&mut self, elem: &'a T) -> Result<HashSet<&'a T>> {
-		let i = self.index(elem)?;
-		let root = Self::find_internal(&mut self.set, i);
-		let avg_set_size = self.set.len() / self.subset_count;
-		let mut subset = HashSet::with_capacity(avg_set_size);
-
-		let set = &mut self.set;
-		self.map
-			.iter()
-			.filter(|(_, &i)| root == Self::find_internal(set, i))
-			.for_each(|(&elem, _)| {
-				subset.insert(elem);
-			});
-
-		Ok(subset)
+		Ok(self.members(elem)?.collect())
 	}
 
 	fn all_subsets(&mut self) -> Vec<HashSet<&'a T>> {
-		let avg_set_size = self.set.len() / self.subset_count;
-		let mut subset_map = HashMap::with_capacity(self.subset_count);
-		let mut subsets = Vec::with_capacity(self.subset_count);
-
-		let set = &mut self.set;
-		self.map.iter().for_each(|(&elem, &i)| {
-			let root = Self::find_internal(set, i);
-			let entry = subset_map.entry(root).or_insert_with(|| {
-				subsets.push(HashSet::with_capacity(avg_set_size));
-				subsets.len() - 1
-			});
-			subsets[*entry].insert(elem);
-		});
-
-		subsets
+		self.iter_subsets().map(Iterator::collect).collect()
 	}
 
 	fn same_subset(&mut self, elem_a: &T, elem_b: &T) -> Result<bool> {
 		let a_i = self.index(elem_a)?;
 		let b_i = self.index(elem_b)?;
 
-		let root_a = Self::find_internal(&mut self.set, a_i);
-		let root_b = Self::find_internal(&mut self.set, b_i);
+		let root_a = self.subsets.find(a_i);
+		let root_b = self.subsets.find(b_i);
 
 		Ok(root_a == root_b)
 	}
 
-	fn subset_count(&self) -> usize { self.subset_count }
+	fn subset_count(&self) -> usize { self.subsets.subset_count() }
 
 	fn subset_size(&mut self, elem: &T) -> Result<usize> {
 		let i = self.index(elem)?;
-		let root = Self::find_internal(&mut self.set, i);
-		Ok(self.set[root].size)
+		let root = self.subsets.find(i);
+		Ok(self.subsets.size(root))
 	}
 }
 
-impl<'a, T> Default for HashDisjointSet<'_, T>
-where T: hash::Hash + Eq
+impl<'a, T: 'a, L, C> ReadonlyUnionFind<'a, T> for StrategyHashDisjointSet<'a, T, L, C>
+where
+	T: hash::Hash + Eq,
+	L: Link,
+	C: Compress,
+{
+	fn find_readonly(&self, elem: &T) -> Result<SubsetTicket<T>> {
+		let i = self.index(elem)?;
+		let root = self.subsets.root(i);
+
+		Ok(self.subsets.ticket(root))
+	}
+
+	fn same_subset_readonly(&self, elem_a: &T, elem_b: &T) -> Result<bool> {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		Ok(self.subsets.root(a_i) == self.subsets.root(b_i))
+	}
+
+	fn subset_size_readonly(&self, elem: &T) -> Result<usize> {
+		let i = self.index(elem)?;
+		Ok(self.subsets.size(self.subsets.root(i)))
+	}
+}
+
+impl<T, L, C> Default for StrategyHashDisjointSet<'_, T, L, C>
+where
+	T: hash::Hash + Eq,
+	L: Link,
 {
 	fn default() -> Self {
-		let disjoint_set = HashDisjointSet {
-			ver: 0,
+		StrategyHashDisjointSet {
 			map: HashMap::new(),
-			set: Vec::new(),
-			subset_count: 0,
-			set_id: SET_ID.load(Ordering::SeqCst),
-		};
-		SET_ID.fetch_add(1, Ordering::SeqCst);
-		disjoint_set
+			elems: Vec::new(),
+			subsets: Subsets::default(),
+		}
 	}
 }
 
-impl<'a, T> iter::FromIterator<&'a T> for HashDisjointSet<'a, T>
-where T: hash::Hash + Eq
+impl<'a, T, L, C> iter::FromIterator<&'a T> for StrategyHashDisjointSet<'a, T, L, C>
+where
+	T: hash::Hash + Eq,
+	L: Link,
 {
 	fn from_iter<I>(iter: I) -> Self
 	where I: IntoIterator<Item = &'a T> {
 		let mut map = HashMap::new();
-		let mut set = Vec::new();
+		let mut elems = Vec::new();
 
 		iter.into_iter().for_each(|elem| {
 			map.entry(elem).or_insert_with(|| {
-				let len = set.len();
-				set.push(Unit {
-					size: 1,
-					parent: len,
-				});
-				len
+				elems.push(elem);
+				elems.len() - 1
 			});
 		});
 
-		let disjoint_set = HashDisjointSet {
-			ver: 0,
-			set,
-			subset_count: map.len(),
+		StrategyHashDisjointSet {
+			subsets: Subsets::new(elems.len()),
 			map,
-			set_id: SET_ID.load(Ordering::SeqCst),
-		};
-		SET_ID.fetch_add(1, Ordering::SeqCst);
-		disjoint_set
+			elems,
+		}
+	}
+}
+
+impl<'a, T, L, C> iter::Extend<(&'a T, &'a T)> for StrategyHashDisjointSet<'a, T, L, C>
+where
+	T: hash::Hash + Eq,
+	L: Link,
+	C: Compress,
+{
+	/// Combines the subsets containing the endpoints of each edge.
+	/// Endpoints that are not in the set yet are inserted first.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::<i32>::default();
+	/// set.extend(vec![(&1, &2), (&3, &4), (&2, &3), (&5, &5)]);
+	///
+	/// assert!(set.same_subset(&1, &4).unwrap());
+	/// assert_eq!(2, set.subset_count());
+	/// ```
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = (&'a T, &'a T)> {
+		iter.into_iter().for_each(|(elem_a, elem_b)| {
+			let _ = self.insert(elem_a);
+			let _ = self.insert(elem_b);
+			self.union(elem_a, elem_b)
+				.expect("both endpoints were inserted");
+		});
 	}
 }
 
-impl<'a, T> HashDisjointSet<'a, T>
-where T: hash::Hash + Eq
+/// Finds the connected components of the graph made of the provided edges.
+///
+/// The vertices are the endpoints of the edges.
+/// Components are ordered by the first appearance of any of their vertices in the edges,
+/// and the vertices of each component are in the order they first appear,
+/// so the result only depends on the order of the edges.
+///
+/// # Examples
+/// ```
+/// # use union_find::hash_disjoint_set::connected_components;
+/// #
+/// let edges = vec![(&"a", &"b"), (&"c", &"d"), (&"e", &"a"), (&"f", &"f")];
+/// let components = connected_components(edges);
+/// let expected = vec![vec![&"a", &"b", &"e"], vec![&"c", &"d"], vec![&"f"]];
+///
+/// assert_eq!(expected, components);
+/// ```
+pub fn connected_components<'a, T, I>(edges: I) -> Vec<Vec<&'a T>>
+where
+	T: hash::Hash + Eq,
+	I: IntoIterator<Item = (&'a T, &'a T)>, {
+	let mut set = HashDisjointSet::<T>::default();
+	set.extend(edges);
+
+	set.subsets
+		.group(set.elems.iter().enumerate().map(|(i, &elem)| (elem, i)))
+}
+
+impl<'a, T, L, C> StrategyHashDisjointSet<'a, T, L, C>
+where
+	T: hash::Hash + Eq,
+	L: Link,
+	C: Compress,
 {
 	/// Adds an element to the `HashDisjointSet`.
 	/// The added element is considered part of a new disjoint subset
@@ -208,33 +273,541 @@ where T: hash::Hash + Eq
 	/// assert_eq!(result, HashDisjointSetError::DuplicateElement);
 	/// ```
 	pub fn insert(&mut self, elem: &'a T) -> Result<()> {
-		let set = &mut self.set;
-
 		if let Entry::Vacant(entry) = self.map.entry(elem) {
-			entry.insert(set.len());
+			entry.insert(self.elems.len());
 			Ok(())
 		} else {
 			Err(HashDisjointSetError::DuplicateElement)
 		}?;
 
-		set.push(Unit {
-			size: 1,
-			parent: set.len(),
-		});
-		self.subset_count += 1;
-		self.ver += 1;
+		self.elems.push(elem);
+		self.subsets.push();
 
 		Ok(())
 	}
 
-	fn find_internal(set: &mut Vec<Unit>, elem: usize) -> usize {
-		let mut elem = elem;
-		while set[elem].parent != elem {
-			let grandparent = set[elem].parent;
-			set[elem].parent = set[grandparent].parent;
-			elem = grandparent;
+	/// Removes an element from the `HashDisjointSet`.
+	/// The other elements in the subset of the removed element remain in the same subset.
+	///
+	/// This operation takes linear time in the number of elements in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b'h').unwrap();
+	/// set.union(&b'h', &b'i').unwrap();
+	/// set.remove(&b'h').unwrap();
+	///
+	/// assert!(set.same_subset(&b'T', &b'i').unwrap());
+	/// assert_eq!(2, set.subset_size(&b'T').unwrap());
+	/// assert_eq!(7, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	/// ```
+	/// # use union_find::hash_disjoint_set::{HashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let result = set.remove(&b'Q').unwrap_err();
+	///
+	/// assert_eq!(result, HashDisjointSetError::ElementNotDefined);
+	/// ```
+	pub fn remove(&mut self, elem: &T) -> Result<()> {
+		let i = self
+			.map
+			.remove(elem)
+			.ok_or(HashDisjointSetError::ElementNotDefined)?;
+
+		self.subsets.swap_remove(i);
+		self.elems.swap_remove(i);
+		if let Some(&moved) = self.elems.get(i) {
+			self.map.insert(moved, i);
 		}
-		elem
+
+		Ok(())
+	}
+
+	/// Detaches an element from its subset, leaving it in a new subset containing only that element.
+	/// The other elements of the subset remain in the same subset.
+	///
+	/// Returns `true` if a new subset was created,
+	/// and `false` if the element was already alone in its subset.
+	///
+	/// This operation takes linear time in the number of elements in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b'h').unwrap();
+	/// set.union(&b'h', &b'i').unwrap();
+	///
+	/// assert!(set.isolate(&b'h').unwrap());
+	/// assert!(!set.isolate(&b'h').unwrap());
+	/// assert!(set.same_subset(&b'T', &b'i').unwrap());
+	/// assert_eq!(8, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn isolate(&mut self, elem: &T) -> Result<bool> {
+		let i = self.index(elem)?;
+		Ok(self.subsets.isolate(i))
+	}
+
+	/// Splits the subset containing the provided element in two.
+	/// The members of the subset for which `predicate` returns `true` are moved together into a new subset,
+	/// and the other members remain in the original subset.
+	///
+	/// Returns `true` if a new subset was created,
+	/// and `false` if `predicate` returned the same answer for every member of the subset.
+	///
+	/// This operation takes linear time in the number of elements in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b'h').unwrap();
+	/// set.union(&b'h', &b'i').unwrap();
+	/// set.union(&b'i', &b's').unwrap();
+	///
+	/// assert!(set.split(&b'T', |&elem| elem < b'i').unwrap());
+	/// assert!(set.same_subset(&b'T', &b'h').unwrap());
+	/// assert!(set.same_subset(&b'i', &b's').unwrap());
+	/// assert!(!set.same_subset(&b'h', &b'i').unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn split<P>(&mut self, elem: &T, mut predicate: P) -> Result<bool>
+	where P: FnMut(&T) -> bool {
+		let i = self.index(elem)?;
+
+		let elems = &self.elems;
+		Ok(self.subsets.split(i, |&i| predicate(elems[i])))
+	}
+
+	/// Combines the subsets of `other` into this set.
+	///
+	/// Elements of `other` that are not in this set are inserted,
+	/// and elements in the same subset of `other` end up in the same subset of this set.
+	/// Elements that are in both sets connect their subsets from each set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(&[1, 2, 3]);
+	/// set.union(&1, &2).unwrap();
+	/// let mut other = HashDisjointSet::from_iter(&[3, 4, 5]);
+	/// other.union(&3, &4).unwrap();
+	/// set.merge_from(&other);
+	///
+	/// assert_eq!(3, set.subset_count());
+	/// assert!(set.same_subset(&3, &4).unwrap());
+	/// assert!(!set.same_subset(&1, &4).unwrap());
+	/// ```
+	pub fn merge_from<L2, C2>(&mut self, other: &StrategyHashDisjointSet<'a, T, L2, C2>)
+	where L2: Link {
+		let mut representatives = HashMap::with_capacity(other.subsets.subset_count());
+
+		self.extend(other.elems.iter().enumerate().map(|(i, &elem)| {
+			let root = other.subsets.root(i);
+			let representative = *representatives.entry(root).or_insert(elem);
+			(representative, elem)
+		}));
+	}
+
+	/// Creates a new set with the subsets of this set and `other` combined,
+	/// as if `merge_from(..)` was called on a copy of this set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set_a = HashDisjointSet::from_iter(&[1, 2, 3]);
+	/// set_a.union(&1, &2).unwrap();
+	/// let mut set_b = HashDisjointSet::from_iter(&[2, 3, 4]);
+	/// set_b.union(&3, &2).unwrap();
+	/// let mut joined = set_a.join(&set_b);
+	///
+	/// assert_eq!(2, joined.subset_count());
+	/// assert!(joined.same_subset(&1, &3).unwrap());
+	/// assert_eq!(2, set_a.subset_count());
+	/// ```
+	pub fn join<L2, C2>(&self, other: &StrategyHashDisjointSet<'a, T, L2, C2>) -> Self
+	where L2: Link {
+		let mut set = Self::default();
+		set.merge_from(self);
+		set.merge_from(other);
+		set
+	}
+
+	/// Iterates over the elements in the same subset as the provided element, starting with that element.
+	///
+	/// Unlike `subset_containing(..)`, this takes time proportional to the size of the subset
+	/// and does not allocate.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	/// let mut members = set.members(&b't').unwrap().collect::<Vec<_>>();
+	/// members.sort();
+	///
+	/// assert_eq!(vec![&b'T', &b't'], members);
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn members(&self, elem: &T) -> Result<Members<'_, [&'a T]>> {
+		let i = self.index(elem)?;
+		Ok(self.subsets.members(&self.elems[..], i))
+	}
+
+	/// Iterates over one element of each subset.
+	///
+	/// The representative of a subset is the element identified by `find(..)`,
+	/// so it can change whenever the set is modified.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	///
+	/// assert_eq!(8, set.representatives().count());
+	/// ```
+	pub fn representatives(&self) -> impl Iterator<Item = &'a T> + '_ {
+		let elems = &self.elems;
+		self.subsets.roots().map(move |root| elems[root])
+	}
+
+	/// Iterates over the subsets, each of which is an iterator over its members.
+	///
+	/// Unlike `all_subsets()`, this does not allocate.
+	/// The order of the subsets and of the elements in each subset is arbitrary.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	/// set.union(&b'a', &b't').unwrap();
+	/// let largest = set.iter_subsets().map(|subset| subset.count()).max();
+	///
+	/// assert_eq!(Some(3), largest);
+	/// ```
+	pub fn iter_subsets(&self) -> impl Iterator<Item = Members<'_, [&'a T]>> + '_ {
+		self.subsets.iter_subsets(&self.elems[..])
+	}
+
+	/// Identify the subset of an element with a ticket that stays valid until that subset changes.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let ticket = set.find_stable(&b'T').unwrap();
+	/// set.union(&b'a', &b'e').unwrap();
+	///
+	/// assert!(set.is_valid(&ticket));
+	/// assert_eq!(ticket, set.find_stable(&b'T').unwrap());
+	///
+	/// set.union(&b'T', &b't').unwrap();
+	/// assert!(!set.is_valid(&ticket));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn find_stable(&mut self, elem: &T) -> Result<StableTicket<T>> {
+		let i = self.index(elem)?;
+		Ok(self.subsets.find_stable(i))
+	}
+
+	/// Determine if a `StableTicket` taken from this set still identifies a subset,
+	/// meaning the subset has not changed since the ticket was taken.
+	pub fn is_valid(&self, ticket: &StableTicket<T>) -> bool { self.subsets.is_valid(ticket) }
+
+	/// Determine if a `SubsetTicket` was taken from this set since its last modification.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let ticket = set.find(&b'T').unwrap();
+	/// assert!(set.is_current(&ticket));
+	///
+	/// set.union(&b'T', &b't').unwrap();
+	/// assert!(!set.is_current(&ticket));
+	/// ```
+	pub fn is_current(&self, ticket: &SubsetTicket<T>) -> bool { self.subsets.is_current(ticket) }
+
+	/// Get a current ticket for the subset that the subset identified by `ticket` has become part of.
+	///
+	/// A ticket identifies its subset through one of its members,
+	/// so after `isolate(..)` or `split(..)` the refreshed ticket follows that member.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let ticket = set.find(&b'T').unwrap();
+	/// set.union(&b't', &b'T').unwrap();
+	/// let ticket = set.refresh(ticket).unwrap();
+	///
+	/// assert!(set.is_current(&ticket));
+	/// assert_eq!(ticket, set.find(&b't').unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the ticket was taken from a different set,
+	/// or if an element has been removed from this set since the ticket was taken.
+	/// ```
+	/// # use union_find::hash_disjoint_set::{HashDisjointSet, StaleTicket};
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let ticket = set.find(&b'T').unwrap();
+	/// set.remove(&b'.').unwrap();
+	///
+	/// assert_eq!(Err(StaleTicket), set.refresh(ticket));
+	/// ```
+	pub fn refresh(
+		&mut self,
+		ticket: SubsetTicket<T>,
+	) -> result::Result<SubsetTicket<T>, StaleTicket> {
+		self.subsets.refresh(ticket)
+	}
+
+	/// Get the number of elements in the subset identified by a current ticket.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	/// let ticket = set.find(&b'T').unwrap();
+	///
+	/// assert_eq!(Ok(2), set.subset_size_by_ticket(&ticket));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the ticket is not current.
+	pub fn subset_size_by_ticket(
+		&self,
+		ticket: &SubsetTicket<T>,
+	) -> result::Result<usize, StaleTicket> {
+		self.subsets
+			.ticket_root(ticket)
+			.map(|root| self.subsets.size(root))
+	}
+
+	/// Iterates over the elements in the subset identified by a current ticket.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	/// let ticket = set.find(&b'T').unwrap();
+	/// let mut members = set.members_by_ticket(&ticket).unwrap().collect::<Vec<_>>();
+	/// members.sort();
+	///
+	/// assert_eq!(vec![&b'T', &b't'], members);
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the ticket is not current.
+	pub fn members_by_ticket(
+		&self,
+		ticket: &SubsetTicket<T>,
+	) -> result::Result<Members<'_, [&'a T]>, StaleTicket> {
+		let root = self.subsets.ticket_root(ticket)?;
+		Ok(self.subsets.members(&self.elems[..], root))
+	}
+
+	/// Adds an element to the `HashDisjointSet` like `insert(..)`,
+	/// but a failure also reports the element that caused it.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::{Argument, HashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let error = set.try_insert(&b'T').unwrap_err();
+	///
+	/// assert_eq!(&HashDisjointSetError::DuplicateElement, error.kind());
+	/// assert_eq!(&&b'T', error.element());
+	/// assert_eq!(Argument::First, error.argument());
+	/// ```
+	pub fn try_insert(&mut self, elem: &'a T) -> Attempt<'a, (), T> {
+		self.insert(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Combine the subsets containing each element like `union(..)`,
+	/// but a failure also reports the missing element and which argument it was.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::{Argument, HashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let error = set.try_union(&b'T', &b'Q').unwrap_err();
+	///
+	/// assert_eq!(&&b'Q', error.element());
+	/// assert_eq!(Argument::Second, error.argument());
+	/// ```
+	pub fn try_union(&mut self, elem_a: &'a T, elem_b: &'a T) -> Attempt<'a, (), T> {
+		let a_i = self.locate(elem_a, Argument::First)?;
+		let b_i = self.locate(elem_b, Argument::Second)?;
+
+		self.subsets.union(a_i, b_i);
+
+		Ok(())
+	}
+
+	/// Determine if two elements are in the same subset like `same_subset(..)`,
+	/// but a failure also reports the missing element and which argument it was.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::{Argument, HashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let error = set.try_same_subset(&b'Q', &b'T').unwrap_err();
+	///
+	/// let expected = "The provided element is not defined in this set. (element 81, first argument)";
+	///
+	/// assert_eq!(Argument::First, error.argument());
+	/// assert_eq!(expected, error.to_string());
+	/// ```
+	pub fn try_same_subset<'e>(&mut self, elem_a: &'e T, elem_b: &'e T) -> Attempt<'e, bool, T> {
+		let a_i = self.locate(elem_a, Argument::First)?;
+		let b_i = self.locate(elem_b, Argument::Second)?;
+
+		Ok(self.subsets.find(a_i) == self.subsets.find(b_i))
+	}
+
+	/// Identify the subset of an element like `find(..)`,
+	/// but a failure also reports the missing element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::{Argument, HashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let error = set.try_find(&b'Q').unwrap_err();
+	///
+	/// assert_eq!(&&b'Q', error.element());
+	/// assert_eq!(Argument::First, error.argument());
+	/// ```
+	pub fn try_find(&mut self, elem: &'a T) -> Attempt<'a, SubsetTicket<T>, T> {
+		self.find(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Get the number of elements in the subset containing the provided element like `subset_size(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_subset_size(&mut self, elem: &'a T) -> Attempt<'a, usize, T> {
+		self.subset_size(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Get all the elements in the same subset as the provided element like `subset_containing(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_subset_containing(&mut self, elem: &'a T) -> Attempt<'a, HashSet<&'a T>, T> {
+		self.subset_containing(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Removes an element from the `HashDisjointSet` like `remove(..)`,
+	/// but a failure also reports the missing element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::{HashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.try_remove(&b'T').unwrap();
+	/// let error = set.try_remove(&b'T').unwrap_err();
+	///
+	/// assert_eq!(&HashDisjointSetError::ElementNotDefined, error.kind());
+	/// assert_eq!(&&b'T', error.element());
+	/// ```
+	pub fn try_remove<'e>(&mut self, elem: &'e T) -> Attempt<'e, (), T> {
+		self.remove(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Detaches an element from its subset like `isolate(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_isolate<'e>(&mut self, elem: &'e T) -> Attempt<'e, bool, T> {
+		self.isolate(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Splits the subset containing the provided element in two like `split(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_split<'e, P>(&mut self, elem: &'e T, predicate: P) -> Attempt<'e, bool, T>
+	where P: FnMut(&T) -> bool {
+		self.split(elem, predicate)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	fn locate<'e>(&self, elem: &'e T, argument: Argument) -> Attempt<'e, usize, T> {
+		self.map.get(elem).copied().ok_or_else(|| {
+			ElementError::new(HashDisjointSetError::ElementNotDefined, elem, argument)
+		})
 	}
 
 	fn index(&self, elem: &T) -> Result<usize> {
@@ -245,8 +818,26 @@ where T: hash::Hash + Eq
 	}
 }
 
-impl<'a, T> HashDisjointSet<'a, T>
-where T: hash::Hash + Eq + Debug
+#[cfg(feature = "serde")]
+impl<T, L, C> Serialize for StrategyHashDisjointSet<'_, T, L, C>
+where
+	T: hash::Hash + Eq + Serialize,
+	L: Link,
+{
+	fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+	where S: Serializer {
+		serializer.collect_seq(
+			self.subsets
+				.group(self.elems.iter().enumerate().map(|(i, &elem)| (elem, i))),
+		)
+	}
+}
+
+impl<'a, T, L, C> StrategyHashDisjointSet<'a, T, L, C>
+where
+	T: hash::Hash + Eq + Debug,
+	L: Link,
+	C: Compress,
 {
 	/// Pretty prints a `HashDisjointSet` for debugging purposes.
 	///
@@ -1,7 +1,11 @@
+// These tests are included once for each combination of strategies by `strategy_tests.rs`,
+// which also defines `HashDisjointSet` with those strategies.
+
 use std::{collections::HashSet, hash::Hash, iter::FromIterator};
 
 use crate::{
-	hash_disjoint_set::{HashDisjointSet, HashDisjointSetError},
+	hash_disjoint_set::{connected_components, Argument, HashDisjointSetError, StaleTicket},
+	ReadonlyUnionFind,
 	SubsetTicket,
 	UnionFind,
 };
@@ -393,6 +397,72 @@ fn all_subsets() {
 	assert_eq!(expected, actual);
 }
 
+#[test]
+fn readonly_find_eq_find() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b's', &b'e').unwrap();
+	set.union(&b't', &b'T').unwrap();
+	set.union(&b'e', &b'T').unwrap();
+
+	let ticket = set.find_readonly(&b's').unwrap();
+	assert_eq!(ticket, set.find_readonly(&b'T').unwrap());
+	assert_eq!(ticket, set.find(&b's').unwrap());
+	assert_ne!(ticket, set.find_readonly(&b'h').unwrap());
+}
+
+#[test]
+fn readonly_find_err() {
+	let set = HashDisjointSet::from_iter(b"This is a test.");
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.find_readonly(&b'Q')
+	);
+}
+
+#[test]
+fn readonly_same_subset() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b't', &b'T').unwrap();
+	assert!(set.same_subset_readonly(&b'T', &b't').unwrap());
+	assert!(!set.same_subset_readonly(&b'T', &b'h').unwrap());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.same_subset_readonly(&b'T', &b'Q')
+	);
+}
+
+#[test]
+fn readonly_subset_size() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+	assert_eq!(3, set.subset_size_readonly(&b'h').unwrap());
+	assert_eq!(1, set.subset_size_readonly(&b'.').unwrap());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_size_readonly(&b'Q')
+	);
+}
+
+#[test]
+fn readonly_shared_between_threads() {
+	use std::{sync::RwLock, thread};
+
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	let lock = RwLock::new(set);
+
+	thread::scope(|scope| {
+		(0..4).for_each(|_| {
+			scope.spawn(|| {
+				let set = lock.read().unwrap();
+				assert!(set.same_subset_readonly(&b'h', &b'i').unwrap());
+				assert_eq!(2, set.subset_size_readonly(&b'i').unwrap());
+			});
+		});
+	});
+}
+
 #[derive(Debug, Eq)]
 struct Subsets<'a, T>(&'a [HashSet<&'a T>])
 where T: Hash + Eq;
@@ -415,3 +485,675 @@ where T: Hash + Eq
 		expected_subsets.len() == used_i.len() && used_i.len() == subsets.len()
 	}
 }
+
+#[test]
+fn remove_err() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.remove(&b'Q')
+	);
+	assert_eq!(9, set.subset_count());
+}
+
+#[test]
+fn remove_singleton_subset_count() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.remove(&b'T').unwrap();
+	assert_eq!(8, set.subset_count());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.find(&b'T')
+	);
+}
+
+#[test]
+fn remove_root_keeps_subset() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b'h').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+	set.union(&b'T', &b's').unwrap();
+
+	set.remove(&b'T').unwrap();
+
+	assert_eq!(6, set.subset_count());
+	assert_eq!(3, set.subset_size(&b'h').unwrap());
+	assert!(set.same_subset(&b'h', &b'i').unwrap());
+	assert!(set.same_subset(&b'i', &b's').unwrap());
+}
+
+#[test]
+fn remove_child_keeps_subset() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	set.union(&b's', &b'a').unwrap();
+	set.union(&b'h', &b's').unwrap();
+
+	set.remove(&b'a').unwrap();
+	set.remove(&b'i').unwrap();
+
+	assert_eq!(6, set.subset_count());
+	assert_eq!(2, set.subset_size(&b's').unwrap());
+	assert!(set.same_subset(&b'h', &b's').unwrap());
+	assert!(!set.same_subset(&b'h', &b'T').unwrap());
+}
+
+#[test]
+fn remove_reinsert() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	set.remove(&b'i').unwrap();
+	set.insert(&b'i').unwrap();
+
+	assert_eq!(9, set.subset_count());
+	assert!(!set.same_subset(&b'h', &b'i').unwrap());
+	assert_eq!(1, set.subset_size(&b'h').unwrap());
+}
+
+#[test]
+fn remove_all_subsets() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+
+	set.union(&b'a', &b's').unwrap();
+	set.union(&b'e', &b'a').unwrap();
+	set.union(&b'T', &b'.').unwrap();
+	set.remove(&b'a').unwrap();
+	set.remove(&b'.').unwrap();
+	set.remove(&b' ').unwrap();
+
+	let actual = set.all_subsets();
+	let actual = Subsets(&actual[..]);
+	let expected = [
+		HashSet::<&u8>::from_iter(b"T"),
+		HashSet::<&u8>::from_iter(b"h"),
+		HashSet::<&u8>::from_iter(b"i"),
+		HashSet::<&u8>::from_iter(b"es"),
+		HashSet::<&u8>::from_iter(b"t"),
+	];
+	let expected = Subsets(&expected);
+
+	assert_eq!(expected, actual);
+}
+
+#[test]
+fn remove_every_element() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b't').unwrap();
+	b"This is a test."
+		.iter()
+		.for_each(|elem| drop(set.remove(elem)));
+
+	assert_eq!(0, set.subset_count());
+	assert!(set.all_subsets().is_empty());
+}
+
+#[test]
+fn different_ver_remove_find() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+
+	let ticket1 = set.find(&b'T').unwrap();
+	set.remove(&b'Q').unwrap_err();
+	let ticket2 = set.find(&b'T').unwrap();
+	set.remove(&b'h').unwrap();
+	let ticket3 = set.find(&b'T').unwrap();
+
+	assert_eq!(ticket1, ticket2);
+	assert_ne!(ticket2, ticket3);
+}
+
+#[test]
+fn extend_inserts_and_unions() {
+	let mut set = HashDisjointSet::from_iter(b"abc");
+	set.extend(b"ab".iter().zip(b"dc"));
+
+	assert_eq!(2, set.subset_count());
+	assert!(set.same_subset(&b'a', &b'd').unwrap());
+	assert!(!set.same_subset(&b'a', &b'b').unwrap());
+	assert_eq!(2, set.subset_size(&b'c').unwrap());
+}
+
+#[test]
+fn extend_empty() {
+	let mut set = HashDisjointSet::<u8>::default();
+	set.extend(Vec::new());
+	assert_eq!(0, set.subset_count());
+}
+
+#[test]
+fn connected_components_order() {
+	let edges = [(5, 3), (1, 2), (3, 4), (6, 6), (2, 7), (4, 5)];
+	let components = connected_components(edges.iter().map(|(a, b)| (a, b)));
+
+	assert_eq!(
+		vec![vec![&5, &3, &4], vec![&1, &2, &7], vec![&6]],
+		components
+	);
+}
+
+#[test]
+fn connected_components_repeatable() {
+	let edges = b"the quick brown fox jumps over the lazy dog"
+		.windows(2)
+		.map(|pair| (&pair[0], &pair[1]))
+		.collect::<Vec<_>>();
+
+	let expected = connected_components(edges.clone());
+	for _ in 0..10 {
+		assert_eq!(expected, connected_components(edges.clone()));
+	}
+	assert_eq!(1, expected.len());
+	assert_eq!(&b't', expected[0][0]);
+}
+
+#[test]
+fn connected_components_empty() {
+	assert!(connected_components::<u8, _>(Vec::new()).is_empty());
+}
+
+#[test]
+fn isolate_member() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b'h').unwrap();
+	set.union(&b'h', &b'i').unwrap();
+	set.union(&b'i', &b's').unwrap();
+
+	let ticket = set.find(&b's').unwrap();
+	assert!(set.isolate(&b'i').unwrap());
+
+	assert_eq!(7, set.subset_count());
+	assert_eq!(1, set.subset_size(&b'i').unwrap());
+	assert_eq!(3, set.subset_size(&b'T').unwrap());
+	assert!(set.same_subset(&b's', &b'T').unwrap());
+	assert!(!set.same_subset(&b's', &b'i').unwrap());
+	assert_ne!(ticket, set.find(&b's').unwrap());
+}
+
+#[test]
+fn isolate_every_member() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	b"his"
+		.iter()
+		.for_each(|elem| set.union(&b'T', elem).unwrap());
+	for elem in b"This" {
+		set.isolate(elem).unwrap();
+	}
+
+	assert_eq!(9, set.subset_count());
+	assert!(b"This"
+		.iter()
+		.all(|elem| set.subset_size(elem).unwrap() == 1));
+}
+
+#[test]
+fn isolate_singleton() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	let ticket = set.find(&b'T').unwrap();
+
+	assert!(!set.isolate(&b'T').unwrap());
+	assert_eq!(9, set.subset_count());
+	assert_eq!(ticket, set.find(&b'T').unwrap());
+}
+
+#[test]
+fn isolate_err() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.isolate(&b'Q')
+	);
+}
+
+#[test]
+fn split_subset() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	b"hisate"
+		.iter()
+		.for_each(|elem| set.union(&b'T', elem).unwrap());
+
+	let ticket = set.find(&b'T').unwrap();
+	assert!(set.split(&b'a', |elem| b"Tha".contains(elem)).unwrap());
+
+	assert_eq!(4, set.subset_count());
+	assert_eq!(3, set.subset_size(&b'h').unwrap());
+	assert_eq!(4, set.subset_size(&b'e').unwrap());
+	assert_eq!(
+		HashSet::from_iter(b"Tha"),
+		set.subset_containing(&b'a').unwrap()
+	);
+	assert_eq!(
+		HashSet::from_iter(b"iste"),
+		set.subset_containing(&b's').unwrap()
+	);
+	assert_ne!(ticket, set.find(&b'T').unwrap());
+
+	set.union(&b'a', &b'e').unwrap();
+	assert_eq!(7, set.subset_size(&b'T').unwrap());
+}
+
+#[test]
+fn split_no_change() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b'h').unwrap();
+	let ticket = set.find(&b'T').unwrap();
+
+	assert!(!set.split(&b'T', |_| true).unwrap());
+	assert!(!set.split(&b'h', |_| false).unwrap());
+	assert!(!set.split(&b'i', |_| true).unwrap());
+	assert_eq!(8, set.subset_count());
+	assert_eq!(ticket, set.find(&b'T').unwrap());
+}
+
+#[test]
+fn split_err() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.split(&b'Q', |_| true)
+	);
+}
+
+#[test]
+fn merge_from_overlapping() {
+	let mut set = HashDisjointSet::from_iter(b"abcd");
+	set.union(&b'a', &b'b').unwrap();
+	let mut other = HashDisjointSet::from_iter(b"cdef");
+	other.union(&b'b', &b'c').unwrap_err();
+	other.union(&b'c', &b'e').unwrap();
+	other.union(&b'd', &b'f').unwrap();
+
+	set.merge_from(&other);
+
+	assert_eq!(3, set.subset_count());
+	assert_eq!(
+		HashSet::from_iter(b"ce"),
+		set.subset_containing(&b'c').unwrap()
+	);
+	assert_eq!(
+		HashSet::from_iter(b"df"),
+		set.subset_containing(&b'f').unwrap()
+	);
+	assert_eq!(2, other.subset_count());
+}
+
+#[test]
+fn merge_from_connects_shards() {
+	let mut set = HashDisjointSet::from_iter(b"abcd");
+	set.union(&b'a', &b'b').unwrap();
+	set.union(&b'c', &b'd').unwrap();
+	let mut other = HashDisjointSet::from_iter(b"bc");
+	other.union(&b'b', &b'c').unwrap();
+
+	let ticket = set.find(&b'a').unwrap();
+	set.merge_from(&other);
+
+	assert_eq!(1, set.subset_count());
+	assert_eq!(4, set.subset_size(&b'd').unwrap());
+	assert_ne!(ticket, set.find(&b'a').unwrap());
+}
+
+#[test]
+fn merge_from_empty() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	let ticket = set.find(&b'T').unwrap();
+	set.merge_from(&HashDisjointSet::<u8>::default());
+
+	assert_eq!(9, set.subset_count());
+	assert_eq!(ticket, set.find(&b'T').unwrap());
+}
+
+#[test]
+fn join_partitions() {
+	let mut set_a = HashDisjointSet::from_iter(b"This is a test.");
+	set_a.union(&b'T', &b'h').unwrap();
+	let mut set_b = HashDisjointSet::from_iter(b"hello");
+	set_b.union(&b'h', &b'e').unwrap();
+	set_b.union(&b'l', &b'o').unwrap();
+
+	let mut joined = set_a.join(&set_b);
+
+	assert_eq!(8, joined.subset_count());
+	assert_eq!(
+		HashSet::from_iter(b"The"),
+		joined.subset_containing(&b'e').unwrap()
+	);
+	assert_eq!(
+		HashSet::from_iter(b"lo"),
+		joined.subset_containing(&b'l').unwrap()
+	);
+	assert_eq!(8, set_a.subset_count());
+	assert_eq!(joined.subset_count(), set_b.join(&set_a).subset_count());
+}
+
+/// Checks that the member lists agree with the parent pointers.
+fn assert_members_consistent<T: Hash + Eq>(set: &mut HashDisjointSet<T>) {
+	let subsets = set
+		.iter_subsets()
+		.map(|subset| subset.collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+
+	assert_eq!(set.subset_count(), subsets.len());
+	for subset in subsets {
+		assert_eq!(subset.len(), set.subset_size(subset[0]).unwrap());
+		assert_eq!(
+			subset.len(),
+			HashSet::<&T>::from_iter(subset.iter().copied()).len()
+		);
+		assert!(subset
+			.iter()
+			.all(|elem| set.same_subset(subset[0], elem).unwrap()));
+	}
+}
+
+#[test]
+fn members_of_subset() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b't').unwrap();
+	set.union(&b'e', &b't').unwrap();
+
+	let members = set.members(&b'e').unwrap().collect::<Vec<_>>();
+	assert_eq!(&b'e', members[0]);
+	assert_eq!(
+		HashSet::<&u8>::from_iter(b"Tte"),
+		HashSet::from_iter(members)
+	);
+	assert_eq!(vec![&b'.'], set.members(&b'.').unwrap().collect::<Vec<_>>());
+}
+
+#[test]
+fn members_err() {
+	let set = HashDisjointSet::from_iter(b"This is a test.");
+	assert!(set.members(&b'Q').is_err());
+}
+
+#[test]
+fn representatives_one_per_subset() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b't').unwrap();
+	set.union(&b'e', &b't').unwrap();
+
+	let representatives = set.representatives().collect::<Vec<_>>();
+	assert_eq!(7, representatives.len());
+	for (i, a) in representatives.iter().enumerate() {
+		assert_eq!(set.find(a).unwrap(), set.find(a).unwrap());
+		for b in &representatives[i + 1..] {
+			assert!(!set.same_subset(a, b).unwrap());
+		}
+	}
+}
+
+#[test]
+fn iter_subsets_matches_all_subsets() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b't').unwrap();
+	set.union(&b'e', &b't').unwrap();
+	set.union(&b'i', &b's').unwrap();
+
+	let mut expected = set.all_subsets();
+	let actual = set
+		.iter_subsets()
+		.map(HashSet::from_iter)
+		.collect::<Vec<_>>();
+	expected.retain(|subset| !actual.contains(subset));
+
+	assert_eq!(6, actual.len());
+	assert!(expected.is_empty());
+	assert_members_consistent(&mut set);
+}
+
+#[test]
+fn iter_subsets_empty() {
+	let set = HashDisjointSet::<u8>::default();
+	assert_eq!(0, set.iter_subsets().count());
+	assert_eq!(0, set.representatives().count());
+}
+
+#[test]
+fn members_after_changes() {
+	let elems = (0..64).collect::<Vec<u32>>();
+	let mut set = HashDisjointSet::from_iter(&elems);
+	for i in 0..60 {
+		set.union(&elems[i], &elems[(i * 7 + 3) % 60]).unwrap();
+	}
+	assert_members_consistent(&mut set);
+
+	for i in (0..64).step_by(5) {
+		set.remove(&elems[i]).unwrap();
+	}
+	assert_members_consistent(&mut set);
+
+	set.isolate(&elems[1]).unwrap();
+	set.split(&elems[2], |elem| elem % 3 == 0).unwrap();
+	assert_members_consistent(&mut set);
+
+	for i in (1..64).filter(|i| i % 5 != 0).step_by(3) {
+		set.union(&elems[i], &elems[62]).unwrap();
+	}
+	assert_members_consistent(&mut set);
+}
+
+#[test]
+fn try_insert_reports_element() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.try_insert(&b'Q').unwrap();
+
+	let error = set.try_insert(&b's').unwrap_err();
+	assert_eq!(&HashDisjointSetError::DuplicateElement, error.kind());
+	assert_eq!(&&b's', error.element());
+	assert_eq!(Argument::First, error.argument());
+	assert_eq!(
+		HashDisjointSetError::DuplicateElement,
+		HashDisjointSetError::from(error)
+	);
+}
+
+#[test]
+fn try_union_reports_argument() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.try_union(&b'T', &b't').unwrap();
+	assert!(set.try_same_subset(&b'T', &b't').unwrap());
+
+	let first = set.try_union(&b'Q', &b'R').unwrap_err();
+	assert_eq!(&&b'Q', first.element());
+	assert_eq!(Argument::First, first.argument());
+
+	let second = set.try_same_subset(&b'T', &b'R').unwrap_err();
+	assert_eq!(&HashDisjointSetError::ElementNotDefined, second.kind());
+	assert_eq!(&&b'R', second.element());
+	assert_eq!(Argument::Second, second.argument());
+	assert_eq!(
+		"The provided element is not defined in this set. (element 82, second argument)",
+		second.to_string()
+	);
+}
+
+#[test]
+fn try_single_element_operations_report_element() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.try_union(&b'T', &b'h').unwrap();
+	set.try_union(&b'h', &b'i').unwrap();
+
+	assert_eq!(set.find(&b'T').unwrap(), set.try_find(&b'i').unwrap());
+	assert_eq!(3, set.try_subset_size(&b'h').unwrap());
+	assert_eq!(3, set.try_subset_containing(&b'T').unwrap().len());
+	assert!(set.try_split(&b'T', |&elem| elem == b'i').unwrap());
+	assert!(set.try_isolate(&b'h').unwrap());
+	set.try_remove(&b'h').unwrap();
+
+	let errors = vec![
+		set.try_find(&b'h').unwrap_err(),
+		set.try_subset_size(&b'h').unwrap_err(),
+		set.try_subset_containing(&b'h').map(|_| ()).unwrap_err(),
+		set.try_split(&b'h', |_| true).map(|_| ()).unwrap_err(),
+		set.try_isolate(&b'h').map(|_| ()).unwrap_err(),
+		set.try_remove(&b'h').unwrap_err(),
+	];
+	for error in errors {
+		assert_eq!(&HashDisjointSetError::ElementNotDefined, error.kind());
+		assert_eq!(&&b'h', error.element());
+		assert_eq!(Argument::First, error.argument());
+	}
+}
+
+#[test]
+fn ticket_staleness() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	let ticket = set.find(&b'T').unwrap();
+	assert!(set.is_current(&ticket));
+
+	set.union(&b'T', &b'T').unwrap();
+	assert!(set.is_current(&ticket));
+
+	set.insert(&b'Q').unwrap();
+	assert!(!set.is_current(&ticket));
+	assert_eq!(Err(StaleTicket), set.subset_size_by_ticket(&ticket));
+	assert_eq!(Some(StaleTicket), set.members_by_ticket(&ticket).err());
+
+	let other = HashDisjointSet::from_iter(b"This is a test.");
+	assert!(!other.is_current(&ticket));
+}
+
+#[test]
+fn refresh_follows_unions() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	let ticket = set.find(&b'h').unwrap();
+	set.union(&b'T', &b'h').unwrap();
+	set.union(&b'i', &b'T').unwrap();
+
+	let ticket = set.refresh(ticket).unwrap();
+	assert!(set.is_current(&ticket));
+	assert_eq!(ticket, set.find(&b'i').unwrap());
+	assert_eq!(Ok(3), set.subset_size_by_ticket(&ticket));
+
+	let members = set
+		.members_by_ticket(&ticket)
+		.unwrap()
+		.copied()
+		.collect::<HashSet<u8>>();
+	assert_eq!(HashSet::from_iter(b"Thi".iter().copied()), members);
+
+	let current = set.find(&b'a').unwrap();
+	let ticket = set.find(&b'a').unwrap();
+	assert_eq!(Ok(current), set.refresh(ticket));
+}
+
+#[test]
+fn refresh_rejects_unresolvable_tickets() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	let mut other = HashDisjointSet::from_iter(b"This is a test.");
+	let foreign = other.find(&b'T').unwrap();
+	assert_eq!(Err(StaleTicket), set.refresh(foreign));
+
+	let before = set.find(&b'T').unwrap();
+	set.remove(&b'h').unwrap();
+	let after = set.find(&b'T').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+
+	assert_eq!(Err(StaleTicket), set.refresh(before));
+	assert_eq!(Ok(set.find(&b'i').unwrap()), set.refresh(after));
+}
+
+#[test]
+fn stable_ticket_survives_unrelated_changes() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b'h').unwrap();
+	let ticket = set.find_stable(&b'h').unwrap();
+
+	set.union(&b'a', &b'e').unwrap();
+	set.insert(&b'Q').unwrap();
+	set.isolate(&b'e').unwrap();
+	set.split(&b'a', |_| true).unwrap();
+	set.remove(&b'Q').unwrap();
+
+	assert!(set.is_valid(&ticket));
+	assert_eq!(ticket, set.find_stable(&b'T').unwrap());
+}
+
+#[test]
+fn stable_ticket_invalidated_by_own_subset() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b'h').unwrap();
+	set.union(&b'i', &b's').unwrap();
+
+	let merged = set.find_stable(&b'h').unwrap();
+	let absorbed = set.find_stable(&b's').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+	assert!(!set.is_valid(&merged));
+	assert!(!set.is_valid(&absorbed));
+	assert_ne!(merged, set.find_stable(&b'h').unwrap());
+
+	let before_isolate = set.find_stable(&b'h').unwrap();
+	set.isolate(&b'h').unwrap();
+	assert!(!set.is_valid(&before_isolate));
+
+	let before_split = set.find_stable(&b'T').unwrap();
+	set.split(&b'T', |&elem| elem == b'T').unwrap();
+	assert!(!set.is_valid(&before_split));
+
+	let before_remove = set.find_stable(&b'i').unwrap();
+	set.remove(&b's').unwrap();
+	assert!(!set.is_valid(&before_remove));
+	let ticket = set.find_stable(&b'i').unwrap();
+	assert!(set.is_valid(&ticket));
+}
+
+#[test]
+fn stable_ticket_after_removal_of_moved_root() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	let tickets = b"This a.e"
+		.iter()
+		.map(|elem| set.find_stable(elem).unwrap())
+		.collect::<Vec<_>>();
+
+	set.remove(&b'T').unwrap();
+	let valid = tickets.iter().filter(|ticket| set.is_valid(ticket)).count();
+	assert_eq!(7, valid);
+
+	assert!(!set.is_valid(&tickets[0]));
+	assert!(set.is_valid(&tickets[6]));
+	assert_eq!(tickets[6], set.find_stable(&b'.').unwrap());
+
+	// the inserted element reuses the index the moved element had before
+	set.insert(&b'T').unwrap();
+	assert!(set.is_valid(&tickets[6]));
+	assert_ne!(tickets[6], set.find_stable(&b'T').unwrap());
+
+	set.remove(&b'h').unwrap();
+	assert!(set.is_valid(&tickets[6]));
+	assert_eq!(tickets[6], set.find_stable(&b'.').unwrap());
+
+	set.union(&b'.', &b'e').unwrap();
+	assert!(!set.is_valid(&tickets[6]));
+}
+
+#[test]
+fn stable_ticket_survives_removal_from_other_subset() {
+	let elems = [1, 2, 3, 4, 5];
+	let mut set = HashDisjointSet::from_iter(&elems);
+	set.union(&1, &2).unwrap();
+	set.union(&5, &4).unwrap();
+	let ticket = set.find_stable(&5).unwrap();
+
+	// the root of the subset of 5 is last, so it is moved into the slot of the removed element
+	set.remove(&1).unwrap();
+
+	assert!(set.is_valid(&ticket));
+	assert_eq!(ticket, set.find_stable(&4).unwrap());
+	assert_eq!(2, set.subset_size(&5).unwrap());
+}
+
+#[test]
+fn stable_ticket_as_key() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	let mut cache = std::collections::HashMap::new();
+	cache.insert(set.find_stable(&b'T').unwrap(), "upper");
+	cache.insert(set.find_stable(&b't').unwrap(), "lower");
+
+	set.union(&b'a', &b'e').unwrap();
+	assert_eq!(Some(&"upper"), cache.get(&set.find_stable(&b'T').unwrap()));
+
+	set.union(&b'T', &b't').unwrap();
+	assert_eq!(None, cache.get(&set.find_stable(&b't').unwrap()));
+
+	let other = HashDisjointSet::from_iter(b"This is a test.");
+	assert!(!other.is_valid(&set.find_stable(&b'a').unwrap()));
+}
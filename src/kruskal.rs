@@ -0,0 +1,110 @@
+use std::{hash, iter};
+
+use crate::{disjoint_set::DisjointSet, owned_hash_disjoint_set::OwnedHashDisjointSet};
+
+/// The edges of a minimum spanning forest, in the order they were chosen.
+///
+/// Edges are chosen in order of increasing weight.
+/// Edges with equal weights keep the order they were provided in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningForest<T, W> {
+	edges: Vec<(T, T, W)>,
+}
+
+impl<T, W> SpanningForest<T, W> {
+	/// Get the chosen edges.
+	pub fn edges(&self) -> &[(T, T, W)] { &self.edges }
+
+	/// Take the chosen edges.
+	pub fn into_edges(self) -> Vec<(T, T, W)> { self.edges }
+
+	/// Get the sum of the weights of the chosen edges.
+	pub fn total_weight(&self) -> W
+	where W: for<'w> iter::Sum<&'w W> {
+		self.edges.iter().map(|(_, _, weight)| weight).sum()
+	}
+}
+
+/// Finds a minimum spanning forest of the graph made of the provided weighted edges.
+///
+/// The vertices are the endpoints of the edges and are kept in an `OwnedHashDisjointSet`.
+/// Self-loops and edges that would create a cycle are skipped.
+///
+/// # Examples
+/// ```
+/// # use union_find::kruskal::kruskal;
+/// #
+/// let edges = vec![("a", "b", 4), ("b", "c", 1), ("a", "c", 2), ("d", "e", 7)];
+/// let forest = kruskal(edges);
+///
+/// let expected = [("b", "c", 1), ("a", "c", 2), ("d", "e", 7)];
+///
+/// assert_eq!(&expected, forest.edges());
+/// assert_eq!(10, forest.total_weight());
+/// ```
+pub fn kruskal<T, W, I>(edges: I) -> SpanningForest<T, W>
+where
+	T: hash::Hash + Eq + Clone,
+	W: Ord,
+	I: IntoIterator<Item = (T, T, W)>, {
+	let edges = edges.into_iter().collect::<Vec<_>>();
+
+	let mut set = OwnedHashDisjointSet::default();
+	for (a, b, _) in &edges {
+		let _ = set.insert(a.clone());
+		let _ = set.insert(b.clone());
+	}
+
+	choose(edges, |a, b| {
+		if set.same_subset(a, b).expect("every endpoint was inserted") {
+			false
+		} else {
+			set.union(a, b).expect("every endpoint was inserted");
+			true
+		}
+	})
+}
+
+/// Finds a minimum spanning forest of the graph made of the provided weighted edges
+/// between the vertices `0..n`, where `n` is one more than the largest endpoint.
+///
+/// The vertices are kept in a `DisjointSet`, which avoids hashing.
+/// Self-loops and edges that would create a cycle are skipped.
+///
+/// # Examples
+/// ```
+/// # use union_find::kruskal::kruskal_dense;
+/// #
+/// let edges = vec![(0, 1, 4), (1, 2, 1), (0, 2, 2), (3, 4, 7)];
+/// let forest = kruskal_dense(edges);
+///
+/// assert_eq!(&[(1, 2, 1), (0, 2, 2), (3, 4, 7)], forest.edges());
+/// assert_eq!(10, forest.total_weight());
+/// ```
+pub fn kruskal_dense<W, I>(edges: I) -> SpanningForest<usize, W>
+where
+	W: Ord,
+	I: IntoIterator<Item = (usize, usize, W)>, {
+	let edges = edges.into_iter().collect::<Vec<_>>();
+
+	let n = edges
+		.iter()
+		.map(|&(a, b, _)| a.max(b) + 1)
+		.max()
+		.unwrap_or(0);
+	let mut set = DisjointSet::new(n);
+
+	choose(edges, |&a, &b| set.union(a, b))
+}
+
+/// Keeps the edges in order of increasing weight for which `link` combined two subsets.
+fn choose<T, W, F>(mut edges: Vec<(T, T, W)>, mut link: F) -> SpanningForest<T, W>
+where
+	W: Ord,
+	F: FnMut(&T, &T) -> bool, {
+	edges.sort_by(|(_, _, x), (_, _, y)| x.cmp(y));
+
+	SpanningForest {
+		edges: edges.into_iter().filter(|(a, b, _)| link(a, b)).collect(),
+	}
+}
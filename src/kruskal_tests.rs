@@ -0,0 +1,86 @@
+use crate::kruskal::{kruskal, kruskal_dense};
+
+#[test]
+fn empty() {
+	let forest = kruskal(Vec::<(u8, u8, u32)>::new());
+	assert!(forest.edges().is_empty());
+	assert_eq!(0, forest.total_weight());
+	assert!(kruskal_dense(Vec::<(usize, usize, u32)>::new())
+		.edges()
+		.is_empty());
+}
+
+#[test]
+fn self_loops_skipped() {
+	let forest = kruskal(vec![("a", "a", 1), ("a", "b", 5)]);
+	assert_eq!(&[("a", "b", 5)], forest.edges());
+	assert!(kruskal_dense(vec![(1, 1, 0)]).edges().is_empty());
+}
+
+#[test]
+fn minimum_spanning_tree() {
+	// the graph from the Wikipedia article on Kruskal's algorithm
+	let edges = vec![
+		('A', 'B', 7),
+		('A', 'D', 5),
+		('B', 'C', 8),
+		('B', 'D', 9),
+		('B', 'E', 7),
+		('C', 'E', 5),
+		('D', 'E', 15),
+		('D', 'F', 6),
+		('E', 'F', 8),
+		('E', 'G', 9),
+		('F', 'G', 11),
+	];
+	let forest = kruskal(edges);
+
+	assert_eq!(6, forest.edges().len());
+	assert_eq!(39, forest.total_weight());
+}
+
+#[test]
+fn ties_keep_input_order() {
+	let forest = kruskal_dense(vec![(0, 1, 1), (1, 2, 1), (0, 2, 1)]);
+	assert_eq!(vec![(0, 1, 1), (1, 2, 1)], forest.into_edges());
+}
+
+#[test]
+fn spanning_forest() {
+	let edges = vec![(0, 1, 3), (2, 3, 1), (1, 0, 2), (5, 6, 9), (3, 2, 4)];
+	let forest = kruskal_dense(edges.clone());
+
+	assert_eq!(&[(2, 3, 1), (1, 0, 2), (5, 6, 9)], forest.edges());
+	assert_eq!(12, forest.total_weight());
+	assert_eq!(forest.into_edges(), kruskal(edges).into_edges());
+}
+
+#[test]
+fn non_numeric_weights() {
+	let forest = kruskal(vec![
+		("x", "y", "banana"),
+		("y", "z", "apple"),
+		("x", "z", "cherry"),
+	]);
+	assert_eq!(&[("y", "z", "apple"), ("x", "y", "banana")], forest.edges());
+}
+
+#[test]
+fn dense_matches_hashed() {
+	let mut state = 0x9e37_79b9_u32;
+	let mut next = || {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		state as usize
+	};
+	let edges = (0..500)
+		.map(|_| (next() % 100, next() % 100, next() % 50))
+		.collect::<Vec<_>>();
+
+	let dense = kruskal_dense(edges.clone());
+	let hashed = kruskal(edges);
+
+	assert_eq!(dense.total_weight(), hashed.total_weight());
+	assert_eq!(dense.edges().len(), hashed.edges().len());
+}
@@ -1,12 +1,68 @@
 //! This crate allows users to work with the union and find operations for disjoint sets.
-use std::{collections::HashSet, error::Error, hash::Hash, iter, marker::PhantomData};
+use std::{
+	collections::HashSet,
+	error::Error,
+	hash::Hash,
+	iter,
+	marker::PhantomData,
+	sync::atomic::{AtomicUsize, Ordering},
+};
 
 mod error;
+mod subsets;
+mod unit;
 
+pub mod clustering;
+pub mod concurrent_disjoint_set;
+pub mod disjoint_set;
+pub mod grid_labeling;
 pub mod hash_disjoint_set;
+pub mod kruskal;
+pub mod offline_lca;
+pub mod owned_hash_disjoint_set;
+pub mod parity_hash_disjoint_set;
+pub mod percolation;
+pub mod persistent_disjoint_set;
+pub mod rollback_hash_disjoint_set;
+pub mod strategy;
+pub mod timestamped_hash_disjoint_set;
+pub mod valued_hash_disjoint_set;
+pub mod weighted_hash_disjoint_set;
 
 #[cfg(test)]
-mod hash_disjoint_set_tests;
+mod clustering_tests;
+#[cfg(test)]
+mod concurrent_disjoint_set_tests;
+#[cfg(test)]
+mod disjoint_set_tests;
+#[cfg(test)]
+mod grid_labeling_tests;
+#[cfg(test)]
+mod kruskal_tests;
+#[cfg(test)]
+mod offline_lca_tests;
+#[cfg(test)]
+mod owned_hash_disjoint_set_tests;
+#[cfg(test)]
+mod parity_hash_disjoint_set_tests;
+#[cfg(test)]
+mod percolation_tests;
+#[cfg(test)]
+mod persistent_disjoint_set_tests;
+#[cfg(test)]
+mod rollback_hash_disjoint_set_tests;
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests;
+#[cfg(test)]
+mod strategy_tests;
+#[cfg(test)]
+mod timestamped_hash_disjoint_set_tests;
+#[cfg(test)]
+mod valued_hash_disjoint_set_tests;
+#[cfg(test)]
+mod weighted_hash_disjoint_set_tests;
+
+static SET_ID: AtomicUsize = AtomicUsize::new(0);
 
 /// This trait should be applied to set structures
 /// that store disjoint subsets and can find information
@@ -216,11 +272,121 @@ where
 	fn subset_size(&mut self, elem: &'a T) -> Result<usize, Self::UnionFindError>;
 }
 
+/// This trait should be applied to `UnionFind` implementors
+/// that can also answer queries through a shared reference.
+///
+/// The methods walk to the root of a subset without compressing paths,
+/// so they can be used behind an `&` reference or a read guard, at the cost of slower lookups.
+/// The results always agree with the corresponding methods of `UnionFind`.
+pub trait ReadonlyUnionFind<'a, T: 'a>: UnionFind<'a, T>
+where T: Hash + Eq {
+	/// Identify the subset of an element without modifying the set.
+	///
+	/// The returned `SubsetTicket` is equal to the one `find(..)` would return.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::{ReadonlyUnionFind, UnionFind};
+	/// use std::sync::RwLock;
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	/// let lock = RwLock::new(set);
+	///
+	/// let set = lock.read().unwrap();
+	/// let ticket = set.find_readonly(&b'T').unwrap();
+	///
+	/// assert_eq!(ticket, set.find_readonly(&b't').unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	/// ```
+	/// # use union_find::hash_disjoint_set::{HashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// # use union_find::ReadonlyUnionFind;
+	/// #
+	/// let set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let result = set.find_readonly(&b'Q').unwrap_err();
+	///
+	/// assert_eq!(HashDisjointSetError::ElementNotDefined, result);
+	/// ```
+	fn find_readonly(&self, elem: &'a T) -> Result<SubsetTicket<T>, Self::UnionFindError>;
+
+	/// Determine if two elements are in the same subset without modifying the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::{ReadonlyUnionFind, UnionFind};
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	/// let set = &set;
+	///
+	/// assert!(set.same_subset_readonly(&b'T', &b't').unwrap());
+	/// assert!(!set.same_subset_readonly(&b'T', &b'a').unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	/// ```
+	/// # use union_find::hash_disjoint_set::{HashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// # use union_find::ReadonlyUnionFind;
+	/// #
+	/// let set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let result = set.same_subset_readonly(&b't', &b'Q').unwrap_err();
+	///
+	/// assert_eq!(HashDisjointSetError::ElementNotDefined, result);
+	/// ```
+	fn same_subset_readonly(
+		&self,
+		elem_a: &'a T,
+		elem_b: &'a T,
+	) -> Result<bool, Self::UnionFindError>;
+
+	/// Get the number of elements in the subset containing the provided element without modifying the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::hash_disjoint_set::HashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::{ReadonlyUnionFind, UnionFind};
+	/// #
+	/// let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b't').unwrap();
+	/// let set = &set;
+	///
+	/// assert_eq!(2, set.subset_size_readonly(&b't').unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	/// ```
+	/// # use union_find::hash_disjoint_set::{HashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// # use union_find::ReadonlyUnionFind;
+	/// #
+	/// let set = HashDisjointSet::from_iter(b"This is a test.");
+	/// let result = set.subset_size_readonly(&b'Q').unwrap_err();
+	///
+	/// assert_eq!(HashDisjointSetError::ElementNotDefined, result);
+	/// ```
+	fn subset_size_readonly(&self, elem: &'a T) -> Result<usize, Self::UnionFindError>;
+}
+
 /// A type returned by the `find(..)` function to allow checking if elements are in the same group
 ///
 /// Two SubsetTickets will not be equal if they originate from the different instances of an implementor of the UnionFind trait.
 /// If an implementor of UnionFind is modified, then SubsetTickets created from that instance of the implementor after the modification will never equal SubsetTickets created before.
 /// A union operation that combines two elements already in the same subset is not considered a modification.
+///
+/// The hashed disjoint sets can tell whether a ticket is still current with `is_current(..)`,
+/// and can `refresh(..)` an outdated ticket to identify the subset its subset has become part of.
 #[derive(Debug, Hash, Eq, PartialEq)]
 pub struct SubsetTicket<T> {
 	id: usize,
@@ -228,3 +394,20 @@ pub struct SubsetTicket<T> {
 	set_id: usize,
 	phantom: PhantomData<T>,
 }
+
+/// A type returned by `find_stable(..)` that identifies a subset for as long as that subset is unchanged.
+///
+/// Unlike a `SubsetTicket`, a `StableTicket` is not affected by changes to other subsets,
+/// so it can be kept as a `HashMap` key for data about a subset across updates to the set.
+/// The ticket stops being valid once its subset is combined with another subset, split, or loses an element,
+/// and tickets taken after such a change are never equal to tickets taken before it.
+#[derive(Debug, Hash, Eq, PartialEq)]
+pub struct StableTicket<T> {
+	id: usize,
+	generation: usize,
+	set_id: usize,
+	phantom: PhantomData<T>,
+}
+
+/// Returns an id that is unique to each disjoint set instance, used to tell `SubsetTicket`s apart.
+pub(crate) fn next_set_id() -> usize { SET_ID.fetch_add(1, Ordering::SeqCst) }
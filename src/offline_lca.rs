@@ -0,0 +1,146 @@
+use crate::disjoint_set::DisjointSet;
+
+/// Finds the lowest common ancestor of each pair of nodes in a rooted tree,
+/// using Tarjan's offline algorithm.
+///
+/// The nodes are `0..children.len()` and `children[node]` lists the children of `node`.
+/// The answers are in the same order as the queries.
+/// A query has no answer if one of its nodes cannot be reached from `root`.
+///
+/// The tree is traversed once without recursion, and the subtrees that have been finished
+/// are kept in a `DisjointSet`, so the whole batch takes nearly linear time
+/// in the number of nodes and queries.
+///
+/// # Examples
+/// ```
+/// # use union_find::offline_lca::offline_lca;
+/// #
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let children = vec![vec![1, 2], vec![3, 4], vec![], vec![], vec![]];
+/// let lca = offline_lca(&children, 0, &[(3, 4), (3, 2), (4, 1), (2, 2)]);
+///
+/// assert_eq!(vec![Some(1), Some(0), Some(1), Some(2)], lca);
+/// ```
+///
+/// # Panics
+/// Panics if `root`, a child, or a node of a query is not less than `children.len()`.
+/// The lists must describe a tree, so no node can be the child of more than one node.
+pub fn offline_lca(
+	children: &[Vec<usize>],
+	root: usize,
+	queries: &[(usize, usize)],
+) -> Vec<Option<usize>> {
+	let mut lca = Lca::new(children.len(), queries);
+	lca.traverse(children, root);
+	lca.answers
+}
+
+/// Finds the lowest common ancestor of each pair of nodes in a forest described by the parent of each node,
+/// using Tarjan's offline algorithm.
+///
+/// The nodes are `0..parents.len()`, and the nodes without a parent are the roots of the trees.
+/// The answers are in the same order as the queries.
+/// A query has no answer if its nodes are in different trees.
+///
+/// # Examples
+/// ```
+/// # use union_find::offline_lca::offline_lca_from_parents;
+/// #
+/// let parents = vec![None, Some(0), Some(0), Some(1), None, Some(4)];
+/// let lca = offline_lca_from_parents(&parents, &[(3, 2), (5, 4), (3, 5)]);
+///
+/// assert_eq!(vec![Some(0), Some(4), None], lca);
+/// ```
+///
+/// # Panics
+/// Panics if a parent or a node of a query is not less than `parents.len()`.
+/// The parents must not form a cycle.
+pub fn offline_lca_from_parents(
+	parents: &[Option<usize>],
+	queries: &[(usize, usize)],
+) -> Vec<Option<usize>> {
+	let mut children = vec![Vec::new(); parents.len()];
+	let mut roots = Vec::new();
+
+	for (node, parent) in parents.iter().enumerate() {
+		match *parent {
+			Some(parent) => children[parent].push(node),
+			None => roots.push(node),
+		}
+	}
+
+	let mut lca = Lca::new(parents.len(), queries);
+	for root in roots {
+		lca.traverse(&children, root);
+	}
+	lca.answers
+}
+
+/// The state of Tarjan's algorithm, shared by the traversals of each tree in a forest.
+struct Lca {
+	set: DisjointSet,
+	/// The node on the current path that the subset of finished nodes hangs from, indexed by the root of the subset.
+	ancestor: Vec<usize>,
+	/// The root of the tree each finished node belongs to.
+	tree: Vec<Option<usize>>,
+	/// The other node and the index of each query, indexed by node.
+	queries: Vec<Vec<(usize, usize)>>,
+	answers: Vec<Option<usize>>,
+}
+
+impl Lca {
+	fn new(n: usize, queries: &[(usize, usize)]) -> Self {
+		let mut by_node = vec![Vec::new(); n];
+		for (i, &(a, b)) in queries.iter().enumerate() {
+			by_node[a].push((b, i));
+			by_node[b].push((a, i));
+		}
+
+		Lca {
+			set: DisjointSet::new(n),
+			ancestor: (0..n).collect(),
+			tree: vec![None; n],
+			queries: by_node,
+			answers: vec![None; queries.len()],
+		}
+	}
+
+	/// Visits the tree below `root` in depth-first order.
+	///
+	/// Once every child of a node is finished, the queries of the node are answered:
+	/// the other node of a query has either not been finished yet,
+	/// or its subset hangs from the lowest common ancestor of the two nodes.
+	fn traverse(&mut self, children: &[Vec<usize>], root: usize) {
+		// each node on the current path and the number of its children that have been visited
+		let mut path = vec![(root, 0)];
+
+		while let Some((node, visited)) = path.last_mut() {
+			let node = *node;
+
+			if let Some(&child) = children[node].get(*visited) {
+				*visited += 1;
+				path.push((child, 0));
+				continue;
+			}
+
+			path.pop();
+			self.tree[node] = Some(root);
+
+			for &(other, i) in &self.queries[node] {
+				if self.tree[other] == Some(root) {
+					self.answers[i] = Some(self.ancestor[self.set.find(other)]);
+				}
+			}
+
+			if let Some(&(parent, _)) = path.last() {
+				self.set.union(parent, node);
+				let subset = self.set.find(parent);
+				self.ancestor[subset] = parent;
+			}
+		}
+	}
+}
@@ -0,0 +1,84 @@
+use crate::offline_lca::{offline_lca, offline_lca_from_parents};
+
+/// Finds the lowest common ancestor by walking up from both nodes.
+fn naive_lca(parents: &[Option<usize>], a: usize, b: usize) -> Option<usize> {
+	let mut ancestors = vec![a];
+	while let Some(parent) = parents[*ancestors.last().unwrap()] {
+		ancestors.push(parent);
+	}
+
+	let mut node = Some(b);
+	while let Some(current) = node {
+		if ancestors.contains(&current) {
+			return Some(current);
+		}
+		node = parents[current];
+	}
+	None
+}
+
+#[test]
+fn empty_queries() {
+	let children = vec![vec![1], vec![]];
+	assert!(offline_lca(&children, 0, &[]).is_empty());
+}
+
+#[test]
+fn single_node() {
+	assert_eq!(vec![Some(0)], offline_lca(&[vec![]], 0, &[(0, 0)]));
+}
+
+#[test]
+fn ancestor_of_other() {
+	let children = vec![vec![1], vec![2], vec![3], vec![]];
+	let lca = offline_lca(&children, 0, &[(3, 1), (1, 3), (0, 2)]);
+	assert_eq!(vec![Some(1), Some(1), Some(0)], lca);
+}
+
+#[test]
+fn unreachable_nodes() {
+	let children = vec![vec![1], vec![], vec![3], vec![]];
+	let lca = offline_lca(&children, 0, &[(1, 3), (2, 3), (0, 1)]);
+	assert_eq!(vec![None, None, Some(0)], lca);
+}
+
+#[test]
+fn subtree_root() {
+	let children = vec![vec![1, 2], vec![3, 4], vec![], vec![], vec![]];
+	let lca = offline_lca(&children, 1, &[(3, 4), (1, 4), (3, 2)]);
+	assert_eq!(vec![Some(1), Some(1), None], lca);
+}
+
+#[test]
+fn deep_path_does_not_overflow() {
+	let n = 200_000;
+	let children = (0..n)
+		.map(|i| if i + 1 < n { vec![i + 1] } else { vec![] })
+		.collect::<Vec<_>>();
+	let lca = offline_lca(&children, 0, &[(n - 1, n / 2), (10, n - 5)]);
+	assert_eq!(vec![Some(n / 2), Some(10)], lca);
+}
+
+#[test]
+fn forest_matches_naive() {
+	let n = 300;
+	// a forest where every tenth node is a root and the others hang from a pseudo-random earlier node
+	let parents = (0..n)
+		.map(|i| {
+			if i % 10 == 0 {
+				None
+			} else {
+				Some((i * 7919) % i)
+			}
+		})
+		.collect::<Vec<_>>();
+	let queries = (0..n)
+		.flat_map(|a| (0..n).step_by(7).map(move |b| (a, b)))
+		.collect::<Vec<_>>();
+
+	let lca = offline_lca_from_parents(&parents, &queries);
+
+	for (&(a, b), answer) in queries.iter().zip(lca) {
+		assert_eq!(naive_lca(&parents, a, b), answer, "query ({}, {})", a, b);
+	}
+}
@@ -0,0 +1,936 @@
+use std::{borrow::Borrow, collections::HashSet, fmt, fmt::Debug, hash, iter, result};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use self::keys::Keys;
+pub use crate::{
+	error::{Argument, ElementError, HashDisjointSetError, StaleTicket},
+	subsets::Members,
+};
+use crate::{
+	strategy::{BySize, PathSplitting},
+	subsets::Subsets,
+	StableTicket,
+	SubsetTicket,
+};
+
+type Result<T> = result::Result<T, HashDisjointSetError>;
+/// The result of a `try_..` method, whose error borrows the element that caused it.
+type Attempt<'e, R, E> = result::Result<R, ElementError<&'e E>>;
+
+/// A disjoint set that owns its elements.
+///
+/// It provides the same operations as `HashDisjointSet`,
+/// but elements are stored by value instead of being borrowed,
+/// so the set can be built from values computed on the fly and returned from functions.
+/// Queries accept any borrowed form of the element type, as with `HashMap`.
+///
+/// Path splitting is used. The union operation is done by size.
+///
+/// With the `serde` feature, an `OwnedHashDisjointSet` is serialized as a list of its subsets,
+/// in the same form as a `HashDisjointSet`.
+pub struct OwnedHashDisjointSet<T>
+where T: hash::Hash + Eq {
+	keys: Keys<T>,
+	subsets: Subsets<BySize, PathSplitting>,
+}
+
+mod keys {
+	use std::{
+		borrow::Borrow,
+		collections::{hash_map::RandomState, HashMap},
+		hash::{BuildHasher, Hash},
+		ops::Index,
+	};
+
+	use crate::subsets::Elements;
+
+	/// The elements of an `OwnedHashDisjointSet` by index.
+	///
+	/// The index of an element is looked up by the hash of the element,
+	/// so each element is only stored once and the element at an index is found directly.
+	pub struct Keys<T> {
+		elems: Vec<T>,
+		hashes: Vec<u64>,
+		/// The first index of the elements with each hash.
+		heads: HashMap<u64, usize>,
+		/// The next index of an element with the same hash as the element at each index.
+		chain: Vec<Option<usize>>,
+		hasher: RandomState,
+	}
+
+	impl<T> Default for Keys<T> {
+		fn default() -> Self {
+			Keys {
+				elems: Vec::new(),
+				hashes: Vec::new(),
+				heads: HashMap::new(),
+				chain: Vec::new(),
+				hasher: RandomState::new(),
+			}
+		}
+	}
+
+	impl<T> Keys<T>
+	where T: Hash + Eq
+	{
+		pub(super) fn len(&self) -> usize { self.elems.len() }
+
+		/// Get the index of `elem`, if it is stored.
+		pub(super) fn get<Q>(&self, elem: &Q) -> Option<usize>
+		where
+			T: Borrow<Q>,
+			Q: Hash + Eq + ?Sized, {
+			let mut next = self.heads.get(&self.hash(elem)).copied();
+			while let Some(i) = next {
+				if self.elems[i].borrow() == elem {
+					return Some(i);
+				}
+				next = self.chain[i];
+			}
+			None
+		}
+
+		/// Stores `elem` at the index `len()`, or gives it back if it is already stored.
+		pub(super) fn push(&mut self, elem: T) -> Result<(), T> {
+			if self.get(&elem).is_some() {
+				return Err(elem);
+			}
+
+			let hash = self.hash(&elem);
+			self.chain.push(self.heads.insert(hash, self.elems.len()));
+			self.hashes.push(hash);
+			self.elems.push(elem);
+
+			Ok(())
+		}
+
+		/// Removes the element at `index` by moving the last element into its place.
+		pub(super) fn swap_remove(&mut self, index: usize) -> T {
+			self.relink(index, self.chain[index]);
+			let last = self.elems.len() - 1;
+			if index != last {
+				self.relink(last, Some(index));
+			}
+
+			self.hashes.swap_remove(index);
+			self.chain.swap_remove(index);
+			self.elems.swap_remove(index)
+		}
+
+		/// Replaces the link to `index` among the indices with the same hash by `to`.
+		fn relink(&mut self, index: usize, to: Option<usize>) {
+			let hash = self.hashes[index];
+			let mut link = self.heads[&hash];
+
+			if link == index {
+				match to {
+					Some(to) => self.heads.insert(hash, to),
+					None => self.heads.remove(&hash),
+				};
+				return;
+			}
+
+			while self.chain[link] != Some(index) {
+				link = self.chain[link].expect("the index is linked from its hash");
+			}
+			self.chain[link] = to;
+		}
+
+		fn hash<Q>(&self, elem: &Q) -> u64
+		where Q: Hash + ?Sized {
+			self.hasher.hash_one(elem)
+		}
+	}
+
+	impl<T> Index<usize> for Keys<T> {
+		type Output = T;
+
+		fn index(&self, index: usize) -> &T { &self.elems[index] }
+	}
+
+	impl<'s, T> Elements<'s> for Keys<T>
+	where T: 's
+	{
+		type Item = &'s T;
+
+		fn element(&'s self, index: usize) -> &'s T { &self.elems[index] }
+	}
+}
+
+impl<T> Default for OwnedHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	fn default() -> Self {
+		OwnedHashDisjointSet {
+			keys: Keys::default(),
+			subsets: Subsets::default(),
+		}
+	}
+}
+
+impl<T> iter::FromIterator<T> for OwnedHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = T> {
+		let mut keys = Keys::default();
+
+		iter.into_iter().for_each(|elem| {
+			let _ = keys.push(elem);
+		});
+
+		OwnedHashDisjointSet {
+			subsets: Subsets::new(keys.len()),
+			keys,
+		}
+	}
+}
+
+impl<T> OwnedHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	/// Adds an element to the `OwnedHashDisjointSet`.
+	/// The added element is considered part of a new disjoint subset
+	/// containing only that element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::default();
+	/// set.insert(String::from("alpha")).unwrap();
+	///
+	/// assert_eq!(1, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is already in the set.
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::{OwnedHashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("alpha")]);
+	/// let result = set.insert(String::from("alpha")).unwrap_err();
+	///
+	/// assert_eq!(HashDisjointSetError::DuplicateElement, result);
+	/// ```
+	pub fn insert(&mut self, elem: T) -> Result<()> {
+		self.keys
+			.push(elem)
+			.map_err(|_| HashDisjointSetError::DuplicateElement)?;
+		self.subsets.push();
+
+		Ok(())
+	}
+
+	/// Removes an element from the `OwnedHashDisjointSet`, returning it.
+	/// The other elements in the subset of the removed element remain in the same subset.
+	///
+	/// This operation takes linear time in the number of elements in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	/// set.union(&2, &3).unwrap();
+	///
+	/// assert_eq!(2, set.remove(&2).unwrap());
+	/// assert!(set.same_subset(&1, &3).unwrap());
+	/// assert_eq!(1, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn remove<Q>(&mut self, elem: &Q) -> Result<T>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+
+		self.subsets.swap_remove(i);
+		Ok(self.keys.swap_remove(i))
+	}
+
+	/// Detaches an element from its subset, leaving it in a new subset containing only that element.
+	/// The other elements of the subset remain in the same subset.
+	///
+	/// Returns `true` if a new subset was created,
+	/// and `false` if the element was already alone in its subset.
+	///
+	/// This operation takes linear time in the number of elements in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..4);
+	/// set.union(&1, &2).unwrap();
+	/// set.union(&2, &3).unwrap();
+	///
+	/// assert!(set.isolate(&2).unwrap());
+	/// assert!(set.same_subset(&1, &3).unwrap());
+	/// assert_eq!(2, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn isolate<Q>(&mut self, elem: &Q) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		Ok(self.subsets.isolate(i))
+	}
+
+	/// Splits the subset containing the provided element in two.
+	/// The members of the subset for which `predicate` returns `true` are moved together into a new subset,
+	/// and the other members remain in the original subset.
+	///
+	/// Returns `true` if a new subset was created,
+	/// and `false` if `predicate` returned the same answer for every member of the subset.
+	///
+	/// This operation takes linear time in the number of elements in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..5);
+	/// set.union(&1, &2).unwrap();
+	/// set.union(&2, &3).unwrap();
+	/// set.union(&3, &4).unwrap();
+	///
+	/// assert!(set.split(&1, |elem| elem % 2 == 0).unwrap());
+	/// assert!(set.same_subset(&1, &3).unwrap());
+	/// assert!(set.same_subset(&2, &4).unwrap());
+	/// assert_eq!(2, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn split<Q, P>(&mut self, elem: &Q, mut predicate: P) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized,
+		P: FnMut(&T) -> bool, {
+		let i = self.index(elem)?;
+
+		let keys = &self.keys;
+		Ok(self.subsets.split(i, |&i| predicate(&keys[i])))
+	}
+
+	/// Combine the subsets containing each element.
+	/// If the two elements are already part of the same set, no change occurs.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("a"), String::from("b")]);
+	/// set.union("a", "b").unwrap();
+	///
+	/// assert_eq!(1, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::{OwnedHashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("a")]);
+	/// let result = set.union("a", "z").unwrap_err();
+	///
+	/// assert_eq!(HashDisjointSetError::ElementNotDefined, result);
+	/// ```
+	pub fn union<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<()>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		self.subsets.union(a_i, b_i);
+
+		Ok(())
+	}
+
+	/// Identify the subset of an element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	///
+	/// assert_eq!(set.find(&1).unwrap(), set.find(&2).unwrap());
+	/// assert_ne!(set.find(&1).unwrap(), set.find(&3).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn find<Q>(&mut self, elem: &Q) -> Result<SubsetTicket<T>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		let root = self.subsets.find(i);
+
+		Ok(self.subsets.ticket(root))
+	}
+
+	/// Identify the subset of an element without modifying the set.
+	///
+	/// Paths are not compressed, so this can be used through a shared reference.
+	/// The returned `SubsetTicket` is equal to the one `find(..)` would return.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	/// let set = &set;
+	///
+	/// let ticket = set.find_readonly(&1).unwrap();
+	///
+	/// assert_eq!(ticket, set.find_readonly(&2).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn find_readonly<Q>(&self, elem: &Q) -> Result<SubsetTicket<T>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		let root = self.subsets.root(i);
+
+		Ok(self.subsets.ticket(root))
+	}
+
+	/// Determine if a `SubsetTicket` was taken from this set since its last modification.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// let ticket = set.find(&1).unwrap();
+	/// assert!(set.is_current(&ticket));
+	///
+	/// set.union(&1, &2).unwrap();
+	/// assert!(!set.is_current(&ticket));
+	/// ```
+	pub fn is_current(&self, ticket: &SubsetTicket<T>) -> bool { self.subsets.is_current(ticket) }
+
+	/// Get a current ticket for the subset that the subset identified by `ticket` has become part of.
+	///
+	/// A ticket identifies its subset through one of its members,
+	/// so after `isolate(..)` or `split(..)` the refreshed ticket follows that member.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// let ticket = set.find(&1).unwrap();
+	/// set.union(&2, &1).unwrap();
+	/// let ticket = set.refresh(ticket).unwrap();
+	///
+	/// assert_eq!(ticket, set.find(&2).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the ticket was taken from a different set,
+	/// or if an element has been removed from this set since the ticket was taken.
+	pub fn refresh(
+		&mut self,
+		ticket: SubsetTicket<T>,
+	) -> result::Result<SubsetTicket<T>, StaleTicket> {
+		self.subsets.refresh(ticket)
+	}
+
+	/// Get the number of elements in the subset identified by a current ticket.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	/// let ticket = set.find(&1).unwrap();
+	///
+	/// assert_eq!(Ok(2), set.subset_size_by_ticket(&ticket));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the ticket is not current.
+	pub fn subset_size_by_ticket(
+		&self,
+		ticket: &SubsetTicket<T>,
+	) -> result::Result<usize, StaleTicket> {
+		self.subsets
+			.ticket_root(ticket)
+			.map(|root| self.subsets.size(root))
+	}
+
+	/// Iterates over the elements in the subset identified by a current ticket.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	/// let ticket = set.find(&1).unwrap();
+	/// let mut members = set.members_by_ticket(&ticket).unwrap().collect::<Vec<_>>();
+	/// members.sort();
+	///
+	/// assert_eq!(vec![&1, &2], members);
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the ticket is not current.
+	pub fn members_by_ticket(
+		&self,
+		ticket: &SubsetTicket<T>,
+	) -> result::Result<Members<'_, Keys<T>>, StaleTicket> {
+		let root = self.subsets.ticket_root(ticket)?;
+		Ok(self.subsets.members(&self.keys, root))
+	}
+
+	/// Identify the subset of an element with a ticket that stays valid until that subset changes.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=4);
+	/// let ticket = set.find_stable(&1).unwrap();
+	/// set.union(&3, &4).unwrap();
+	///
+	/// assert!(set.is_valid(&ticket));
+	/// assert_eq!(ticket, set.find_stable(&1).unwrap());
+	///
+	/// set.union(&1, &2).unwrap();
+	/// assert!(!set.is_valid(&ticket));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn find_stable<Q>(&mut self, elem: &Q) -> Result<StableTicket<T>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		Ok(self.subsets.find_stable(i))
+	}
+
+	/// Determine if a `StableTicket` taken from this set still identifies a subset,
+	/// meaning the subset has not changed since the ticket was taken.
+	pub fn is_valid(&self, ticket: &StableTicket<T>) -> bool { self.subsets.is_valid(ticket) }
+
+	/// Get all the elements in the same subset as the provided element. The provided element is included.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	/// let result = set.subset_containing(&1).unwrap();
+	///
+	/// assert!(result.contains(&2));
+	/// assert!(!result.contains(&3));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_containing<Q>(&mut self, elem: &Q) -> Result<HashSet<&T>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		Ok(self.subsets.members(&self.keys, i).collect())
+	}
+
+	/// Get a list of all the subsets in the disjoint set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	///
+	/// assert_eq!(2, set.all_subsets().len());
+	/// ```
+	pub fn all_subsets(&mut self) -> Vec<HashSet<&T>> {
+		self.subsets
+			.iter_subsets(&self.keys)
+			.map(Iterator::collect)
+			.collect()
+	}
+
+	/// Determine if two elements are in the same subset.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	///
+	/// assert!(set.same_subset(&1, &2).unwrap());
+	/// assert!(!set.same_subset(&1, &3).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn same_subset<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		let root_a = self.subsets.find(a_i);
+		let root_b = self.subsets.find(b_i);
+
+		Ok(root_a == root_b)
+	}
+
+	/// Determine if two elements are in the same subset without modifying the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	/// let set = &set;
+	///
+	/// assert!(set.same_subset_readonly(&1, &2).unwrap());
+	/// assert!(!set.same_subset_readonly(&1, &3).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn same_subset_readonly<Q>(&self, elem_a: &Q, elem_b: &Q) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		Ok(self.subsets.root(a_i) == self.subsets.root(b_i))
+	}
+
+	/// Get the number of disjoint subsets in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let set = OwnedHashDisjointSet::from_iter(1..=3);
+	///
+	/// assert_eq!(3, set.subset_count());
+	/// ```
+	pub fn subset_count(&self) -> usize { self.subsets.subset_count() }
+
+	/// Get the number of elements in the subset containing the provided element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	///
+	/// assert_eq!(2, set.subset_size(&1).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_size<Q>(&mut self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		let root = self.subsets.find(i);
+		Ok(self.subsets.size(root))
+	}
+
+	/// Get the number of elements in the subset containing the provided element without modifying the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::OwnedHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(1..=3);
+	/// set.union(&1, &2).unwrap();
+	/// let set = &set;
+	///
+	/// assert_eq!(2, set.subset_size_readonly(&1).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_size_readonly<Q>(&self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		Ok(self.subsets.size(self.subsets.root(i)))
+	}
+
+	/// Adds an element to the `OwnedHashDisjointSet` like `insert(..)`,
+	/// but a failure also returns the element that was not inserted.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::{HashDisjointSetError, OwnedHashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("alpha")]);
+	/// let error = set.try_insert(String::from("alpha")).unwrap_err();
+	///
+	/// assert_eq!(&HashDisjointSetError::DuplicateElement, error.kind());
+	/// assert_eq!("alpha", error.into_element());
+	/// ```
+	pub fn try_insert(&mut self, elem: T) -> result::Result<(), ElementError<T>> {
+		self.keys.push(elem).map_err(|elem| {
+			ElementError::new(
+				HashDisjointSetError::DuplicateElement,
+				elem,
+				Argument::First,
+			)
+		})?;
+		self.subsets.push();
+
+		Ok(())
+	}
+
+	/// Combine the subsets containing each element like `union(..)`,
+	/// but a failure also reports the missing element and which argument it was.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::{Argument, OwnedHashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("alpha")]);
+	/// let error = set.try_union("alpha", "beta").unwrap_err();
+	///
+	/// assert_eq!(&"beta", error.element());
+	/// assert_eq!(Argument::Second, error.argument());
+	/// ```
+	pub fn try_union<'e, Q>(&mut self, elem_a: &'e Q, elem_b: &'e Q) -> Attempt<'e, (), Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.locate(elem_a, Argument::First)?;
+		let b_i = self.locate(elem_b, Argument::Second)?;
+
+		self.subsets.union(a_i, b_i);
+
+		Ok(())
+	}
+
+	/// Determine if two elements are in the same subset like `same_subset(..)`,
+	/// but a failure also reports the missing element and which argument it was.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::{Argument, OwnedHashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("alpha")]);
+	/// let error = set.try_same_subset("beta", "alpha").unwrap_err();
+	///
+	/// assert_eq!(Argument::First, error.argument());
+	/// ```
+	pub fn try_same_subset<'e, Q>(&mut self, elem_a: &'e Q, elem_b: &'e Q) -> Attempt<'e, bool, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.locate(elem_a, Argument::First)?;
+		let b_i = self.locate(elem_b, Argument::Second)?;
+
+		Ok(self.subsets.find(a_i) == self.subsets.find(b_i))
+	}
+
+	/// Identify the subset of an element like `find(..)`,
+	/// but a failure also reports the missing element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::{Argument, OwnedHashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("alpha")]);
+	/// let error = set.try_find("beta").unwrap_err();
+	///
+	/// assert_eq!(&"beta", error.element());
+	/// assert_eq!(Argument::First, error.argument());
+	/// ```
+	pub fn try_find<'e, Q>(&mut self, elem: &'e Q) -> Attempt<'e, SubsetTicket<T>, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.find(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Get the number of elements in the subset containing the provided element like `subset_size(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_subset_size<'e, Q>(&mut self, elem: &'e Q) -> Attempt<'e, usize, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.subset_size(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Get all the elements in the same subset as the provided element like `subset_containing(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_subset_containing<'e, Q>(&mut self, elem: &'e Q) -> Attempt<'e, HashSet<&T>, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.subset_containing(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Removes an element from the `OwnedHashDisjointSet` like `remove(..)`,
+	/// but a failure also reports the missing element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::owned_hash_disjoint_set::{HashDisjointSetError, OwnedHashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = OwnedHashDisjointSet::from_iter(vec![String::from("alpha")]);
+	/// assert_eq!("alpha", set.try_remove("alpha").unwrap());
+	///
+	/// let error = set.try_remove("alpha").unwrap_err();
+	/// assert_eq!(&HashDisjointSetError::ElementNotDefined, error.kind());
+	/// ```
+	pub fn try_remove<'e, Q>(&mut self, elem: &'e Q) -> Attempt<'e, T, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.remove(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Detaches an element from its subset like `isolate(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_isolate<'e, Q>(&mut self, elem: &'e Q) -> Attempt<'e, bool, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.isolate(elem)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	/// Splits the subset containing the provided element in two like `split(..)`,
+	/// but a failure also reports the missing element.
+	pub fn try_split<'e, Q, P>(&mut self, elem: &'e Q, predicate: P) -> Attempt<'e, bool, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized,
+		P: FnMut(&T) -> bool, {
+		self.split(elem, predicate)
+			.map_err(|kind| ElementError::new(kind, elem, Argument::First))
+	}
+
+	fn locate<'e, Q>(&self, elem: &'e Q, argument: Argument) -> Attempt<'e, usize, Q>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.keys.get(elem).ok_or_else(|| {
+			ElementError::new(HashDisjointSetError::ElementNotDefined, elem, argument)
+		})
+	}
+
+	fn index<Q>(&self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.keys
+			.get(elem)
+			.ok_or(HashDisjointSetError::ElementNotDefined)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T> Serialize for OwnedHashDisjointSet<T>
+where T: hash::Hash + Eq + Serialize
+{
+	fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+	where S: Serializer {
+		serializer.collect_seq(
+			self.subsets
+				.iter_subsets(&self.keys)
+				.map(Iterator::collect::<Vec<_>>),
+		)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for OwnedHashDisjointSet<T>
+where T: hash::Hash + Eq + Deserialize<'de>
+{
+	fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		let subsets = Vec::<Vec<T>>::deserialize(deserializer)?;
+		let mut set = OwnedHashDisjointSet::default();
+
+		for subset in subsets {
+			let first = set.keys.len();
+			for elem in subset {
+				let i = set.keys.len();
+				set.insert(elem).map_err(de::Error::custom)?;
+				set.subsets.union(first, i);
+			}
+		}
+
+		Ok(set)
+	}
+}
+
+impl<T> OwnedHashDisjointSet<T>
+where T: hash::Hash + Eq + Debug
+{
+	/// Pretty prints an `OwnedHashDisjointSet` for debugging purposes.
+	///
+	/// See `HashDisjointSet::fmt(..)` for why the `Debug` trait is not used.
+	pub fn fmt(&mut self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:?}", self.all_subsets())
+	}
+}
@@ -0,0 +1,474 @@
+use std::{collections::HashSet, iter::FromIterator};
+
+use crate::{
+	hash_disjoint_set::{Argument, HashDisjointSetError, StaleTicket},
+	owned_hash_disjoint_set::OwnedHashDisjointSet,
+	SubsetTicket,
+};
+
+fn words() -> OwnedHashDisjointSet<String> {
+	"this is a test of owned elements"
+		.split(' ')
+		.map(String::from)
+		.collect()
+}
+
+#[test]
+fn create_from_iter() { OwnedHashDisjointSet::from_iter(b"This is a test.".to_vec()); }
+
+#[test]
+fn create_default() { OwnedHashDisjointSet::<String>::default(); }
+
+#[test]
+fn from_iter_subset_count() {
+	let set = words();
+	assert_eq!(7, set.subset_count());
+}
+
+#[test]
+fn insert_ok() {
+	let mut set = words();
+	assert_eq!(Ok(()), set.insert(String::from("new")));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn insert_err() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::DuplicateElement),
+		set.insert(String::from("test"))
+	);
+	assert_eq!(7, set.subset_count());
+}
+
+#[test]
+fn insert_computed() {
+	let mut set = OwnedHashDisjointSet::default();
+	(0..10).for_each(|i| set.insert(format!("id-{}", i)).unwrap());
+	set.union("id-3", "id-7").unwrap();
+	assert!(set.same_subset("id-7", "id-3").unwrap());
+	assert_eq!(9, set.subset_count());
+}
+
+#[test]
+fn returned_from_fn() {
+	fn build() -> OwnedHashDisjointSet<String> {
+		let mut set = words();
+		set.union("this", "is").unwrap();
+		set
+	}
+
+	let mut set = build();
+	assert_eq!(2, set.subset_size("is").unwrap());
+}
+
+#[test]
+fn union_ok_change_subset_count() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("a", "is").unwrap();
+	assert_eq!(5, set.subset_count());
+}
+
+#[test]
+fn union_ok_no_change_subset_count() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("a", "is").unwrap();
+	set.union("this", "a").unwrap();
+	assert_eq!(5, set.subset_count());
+}
+
+#[test]
+fn union_err_left() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.union("missing", "this"),
+	);
+}
+
+#[test]
+fn union_err_right() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.union("this", "missing"),
+	);
+}
+
+#[test]
+fn union_ok_change_subset_size() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("a", "is").unwrap();
+	assert_eq!(3, set.subset_size("this").unwrap());
+	assert_eq!(1, set.subset_size("test").unwrap());
+}
+
+#[test]
+fn subset_size_err() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_size("missing")
+	);
+}
+
+#[test]
+fn simple_ne_find() {
+	let mut set = words();
+	assert_ne!(set.find("this").unwrap(), set.find("is").unwrap());
+}
+
+#[test]
+fn union_eq_find() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	assert_eq!(set.find("this").unwrap(), set.find("is").unwrap());
+}
+
+#[test]
+fn different_set_find() {
+	let mut set1 = words();
+	let mut set2 = words();
+	assert_ne!(set1.find("this").unwrap(), set2.find("this").unwrap());
+}
+
+#[test]
+fn different_ver_insert_find() {
+	let mut set = words();
+
+	let ticket1 = set.find("this").unwrap();
+	set.insert(String::from("new")).unwrap();
+	let ticket2 = set.find("this").unwrap();
+
+	assert_ne!(ticket1, ticket2);
+}
+
+#[test]
+fn moved_set_find() {
+	fn move_set(mut set: OwnedHashDisjointSet<String>) -> SubsetTicket<String> {
+		set.find("this").unwrap()
+	}
+
+	let mut set = words();
+
+	let ticket1 = set.find("this").unwrap();
+	let ticket2 = move_set(set);
+
+	assert_eq!(ticket1, ticket2);
+}
+
+#[test]
+fn find_err() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.find("missing")
+	);
+}
+
+#[test]
+fn same_subset_err() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.same_subset("this", "missing"),
+	);
+}
+
+#[test]
+fn subset_containing() {
+	let mut set = words();
+	set.union("of", "owned").unwrap();
+	set.union("elements", "owned").unwrap();
+
+	let subset = set.subset_containing("of").unwrap();
+	let expected = ["of", "owned", "elements"];
+
+	assert_eq!(expected.len(), subset.len());
+	expected
+		.iter()
+		.for_each(|&elem| assert!(subset.contains(&String::from(elem))));
+}
+
+#[test]
+fn subset_containing_err() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_containing("missing"),
+	);
+}
+
+#[test]
+fn all_subsets() {
+	let mut set = OwnedHashDisjointSet::from_iter(1..=6);
+	set.union(&1, &2).unwrap();
+	set.union(&3, &4).unwrap();
+	set.union(&2, &4).unwrap();
+
+	let mut actual = set
+		.all_subsets()
+		.into_iter()
+		.map(|subset| {
+			let mut subset = subset.into_iter().copied().collect::<Vec<_>>();
+			subset.sort_unstable();
+			subset
+		})
+		.collect::<Vec<_>>();
+	actual.sort();
+
+	assert_eq!(vec![vec![1, 2, 3, 4], vec![5], vec![6]], actual);
+}
+
+#[test]
+fn default_all_subsets() {
+	let mut set = OwnedHashDisjointSet::<u8>::default();
+	assert_eq!(Vec::<HashSet<&u8>>::new(), set.all_subsets());
+}
+
+#[test]
+fn remove_ok() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("a", "is").unwrap();
+
+	assert_eq!(Ok(String::from("is")), set.remove("is"));
+	assert_eq!(5, set.subset_count());
+	assert_eq!(2, set.subset_size("a").unwrap());
+	assert!(set.same_subset("this", "a").unwrap());
+}
+
+#[test]
+fn remove_err() {
+	let mut set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.remove("missing")
+	);
+	assert_eq!(7, set.subset_count());
+}
+
+#[test]
+fn remove_singleton() {
+	let mut set = words();
+	set.remove("test").unwrap();
+	assert_eq!(6, set.subset_count());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_size("test")
+	);
+}
+
+#[test]
+fn remove_moved_index() {
+	let mut set = OwnedHashDisjointSet::from_iter(0..5);
+	set.union(&3, &4).unwrap();
+	set.remove(&0).unwrap();
+	set.remove(&1).unwrap();
+
+	assert!(set.same_subset(&4, &3).unwrap());
+	assert!(!set.same_subset(&2, &3).unwrap());
+	assert_eq!(2, set.subset_count());
+}
+
+#[test]
+fn readonly_queries() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("a", "is").unwrap();
+	let shared = &set;
+
+	assert_eq!(
+		shared.find_readonly("this").unwrap(),
+		shared.find_readonly("a").unwrap()
+	);
+	assert!(shared.same_subset_readonly("a", "this").unwrap());
+	assert!(!shared.same_subset_readonly("a", "test").unwrap());
+	assert_eq!(3, shared.subset_size_readonly("is").unwrap());
+	assert_eq!(set.find_readonly("is").unwrap(), set.find("is").unwrap());
+}
+
+#[test]
+fn readonly_err() {
+	let set = words();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.find_readonly("missing")
+	);
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.same_subset_readonly("this", "missing")
+	);
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_size_readonly("missing")
+	);
+}
+
+#[test]
+fn isolate_member() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("is", "a").unwrap();
+
+	assert!(set.isolate("is").unwrap());
+	assert!(!set.isolate("is").unwrap());
+	assert!(set.same_subset("this", "a").unwrap());
+	assert_eq!(1, set.subset_size("is").unwrap());
+	assert_eq!(6, set.subset_count());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.isolate("missing")
+	);
+}
+
+#[test]
+fn split_subset() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("is", "a").unwrap();
+	set.union("a", "test").unwrap();
+
+	let ticket = set.find("a").unwrap();
+	assert!(set.split("a", |elem| elem.len() > 2).unwrap());
+
+	assert!(set.same_subset("this", "test").unwrap());
+	assert!(set.same_subset("is", "a").unwrap());
+	assert!(!set.same_subset("a", "test").unwrap());
+	assert_eq!(2, set.subset_size("is").unwrap());
+	assert_eq!(5, set.subset_count());
+	assert_ne!(ticket, set.find("a").unwrap());
+
+	assert!(!set.split("owned", |_| true).unwrap());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.split("missing", |_| true)
+	);
+}
+
+#[test]
+fn try_insert_returns_element() {
+	let mut set = words();
+	set.try_insert(String::from("new")).unwrap();
+
+	let error = set.try_insert(String::from("test")).unwrap_err();
+	assert_eq!(&HashDisjointSetError::DuplicateElement, error.kind());
+	assert_eq!(Argument::First, error.argument());
+	assert_eq!(
+		"The element is already defined in this set. (element \"test\", first argument)",
+		error.to_string()
+	);
+	assert_eq!("test", error.into_element());
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn try_union_reports_argument() {
+	let mut set = words();
+	set.try_union("this", "test").unwrap();
+	assert!(set.try_same_subset("test", "this").unwrap());
+
+	let error = set.try_union("this", "missing").unwrap_err();
+	assert_eq!(&HashDisjointSetError::ElementNotDefined, error.kind());
+	assert_eq!(&"missing", error.element());
+	assert_eq!(Argument::Second, error.argument());
+
+	let error = set.try_same_subset("missing", "this").unwrap_err();
+	assert_eq!(Argument::First, error.argument());
+}
+
+#[test]
+fn try_single_element_operations_report_element() {
+	let mut set = words();
+	set.try_union("this", "test").unwrap();
+	set.try_union("test", "of").unwrap();
+
+	assert_eq!(set.find("this").unwrap(), set.try_find("of").unwrap());
+	assert_eq!(3, set.try_subset_size("test").unwrap());
+	assert_eq!(3, set.try_subset_containing("this").unwrap().len());
+	assert!(set.try_split("this", |elem| elem == "of").unwrap());
+	assert!(set.try_isolate("test").unwrap());
+	assert_eq!("test", set.try_remove("test").unwrap());
+
+	assert_eq!(&"test", set.try_find("test").unwrap_err().element());
+	assert_eq!(&"test", set.try_subset_size("test").unwrap_err().element());
+	assert_eq!(
+		&"test",
+		set.try_subset_containing("test").unwrap_err().element()
+	);
+	assert_eq!(
+		&"test",
+		set.try_split("test", |_| true).unwrap_err().element()
+	);
+	assert_eq!(&"test", set.try_isolate("test").unwrap_err().element());
+
+	let error = set.try_remove("test").unwrap_err();
+	assert_eq!(&HashDisjointSetError::ElementNotDefined, error.kind());
+	assert_eq!(Argument::First, error.argument());
+}
+
+#[test]
+fn ticket_queries() {
+	let mut set = words();
+	let ticket = set.find("this").unwrap();
+	set.union("is", "this").unwrap();
+	assert!(!set.is_current(&ticket));
+	assert_eq!(Err(StaleTicket), set.subset_size_by_ticket(&ticket));
+
+	let ticket = set.refresh(ticket).unwrap();
+	assert!(set.is_current(&ticket));
+	assert_eq!(Ok(2), set.subset_size_by_ticket(&ticket));
+	assert_eq!(
+		HashSet::from_iter(vec![&String::from("this"), &String::from("is")]),
+		set.members_by_ticket(&ticket)
+			.unwrap()
+			.collect::<HashSet<_>>()
+	);
+
+	set.remove("a").unwrap();
+	assert_eq!(Err(StaleTicket), set.refresh(ticket));
+}
+
+#[test]
+fn stable_tickets() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	let ticket = set.find_stable("is").unwrap();
+
+	set.union("a", "test").unwrap();
+	set.split("a", |elem| elem == "a").unwrap();
+	set.remove("owned").unwrap();
+	assert!(set.is_valid(&ticket));
+	assert_eq!(ticket, set.find_stable("this").unwrap());
+
+	set.isolate("this").unwrap();
+	assert!(!set.is_valid(&ticket));
+
+	let ticket = set.find_stable("a").unwrap();
+	set.union("a", "of").unwrap();
+	assert!(!set.is_valid(&ticket));
+}
+
+#[test]
+fn stable_ticket_survives_removal_from_other_subset() {
+	let mut set = words();
+	set.union("this", "is").unwrap();
+	set.union("elements", "of").unwrap();
+	let ticket = set.find_stable("of").unwrap();
+
+	// the root of the subset of "elements" is last, so it is moved into the slot of the removed element
+	set.remove("this").unwrap();
+
+	assert!(set.is_valid(&ticket));
+	assert_eq!(ticket, set.find_stable("elements").unwrap());
+
+	set.remove("owned").unwrap();
+	assert!(set.is_valid(&ticket));
+
+	set.remove("of").unwrap();
+	assert!(!set.is_valid(&ticket));
+}
@@ -0,0 +1,209 @@
+use std::{borrow::Borrow, hash, iter, result};
+
+pub use crate::error::{HashDisjointSetError, RelationError};
+use crate::weighted_hash_disjoint_set::{Group, WeightedHashDisjointSet};
+
+type Result<T> = result::Result<T, HashDisjointSetError>;
+
+/// The relation between two elements of the same subset of a `ParityHashDisjointSet`.
+///
+/// The parities form a group where combining two opposite relations gives the same relation,
+/// so it can also be used as the weight of a `WeightedHashDisjointSet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Parity {
+	/// The elements are equal, or have the same color.
+	Same,
+	/// The elements are opposite, or have different colors.
+	Opposite,
+}
+
+impl Group for Parity {
+	fn identity() -> Self { Parity::Same }
+
+	fn combine(&self, other: &Self) -> Self {
+		if self == other {
+			Parity::Same
+		} else {
+			Parity::Opposite
+		}
+	}
+
+	fn inverse(&self) -> Self { *self }
+}
+
+/// A disjoint set that owns its elements and records whether elements of the same subset are equal or opposite.
+///
+/// This solves constraints like "`a` and `b` are equal" and "`a` and `b` are opposite",
+/// such as 2-coloring problems, and reports a contradiction when a constraint conflicts with earlier ones.
+/// It is a `WeightedHashDisjointSet` where the weights are `Parity` values.
+///
+/// Path splitting is used. The union operation is done by size.
+pub struct ParityHashDisjointSet<T>
+where T: hash::Hash + Eq {
+	set: WeightedHashDisjointSet<T, Parity>,
+}
+
+impl<T> Default for ParityHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	fn default() -> Self {
+		ParityHashDisjointSet {
+			set: WeightedHashDisjointSet::default(),
+		}
+	}
+}
+
+impl<T> iter::FromIterator<T> for ParityHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = T> {
+		ParityHashDisjointSet {
+			set: iter.into_iter().collect(),
+		}
+	}
+}
+
+impl<T> ParityHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	/// Adds an element to the `ParityHashDisjointSet`.
+	/// The added element is considered part of a new disjoint subset
+	/// containing only that element.
+	///
+	/// # Failures
+	/// An error is returned if the provided element is already in the set.
+	pub fn insert(&mut self, elem: T) -> Result<()> { self.set.insert(elem) }
+
+	/// Records that the two elements are equal, combining the subsets containing each element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::parity_hash_disjoint_set::{Parity, ParityHashDisjointSet};
+	/// #
+	/// let mut set: ParityHashDisjointSet<_> = vec!["a", "b", "c"].into_iter().collect();
+	/// set.union_same("a", "b").unwrap();
+	///
+	/// assert_eq!(Ok(Some(Parity::Same)), set.relation("b", "a"));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set,
+	/// or if the elements are already known to be opposite.
+	pub fn union_same<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> result::Result<(), RelationError>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.set.union_with_diff(elem_a, elem_b, Parity::Same)
+	}
+
+	/// Records that the two elements are opposite, combining the subsets containing each element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::parity_hash_disjoint_set::{Parity, ParityHashDisjointSet};
+	/// #
+	/// let mut set: ParityHashDisjointSet<_> = vec!["a", "b", "c"].into_iter().collect();
+	/// set.union_opposite("a", "b").unwrap();
+	/// set.union_opposite("b", "c").unwrap();
+	///
+	/// assert_eq!(Ok(Some(Parity::Same)), set.relation("a", "c"));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set,
+	/// or if the elements are already known to be equal.
+	/// ```
+	/// # use union_find::parity_hash_disjoint_set::{ParityHashDisjointSet, RelationError};
+	/// #
+	/// let mut set: ParityHashDisjointSet<_> = vec!["a", "b", "c"].into_iter().collect();
+	/// set.union_opposite("a", "b").unwrap();
+	/// set.union_opposite("b", "c").unwrap();
+	/// let result = set.union_opposite("a", "c").unwrap_err();
+	///
+	/// assert_eq!(RelationError::Contradiction, result);
+	/// ```
+	pub fn union_opposite<Q>(
+		&mut self,
+		elem_a: &Q,
+		elem_b: &Q,
+	) -> result::Result<(), RelationError>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized,
+	{
+		self.set.union_with_diff(elem_a, elem_b, Parity::Opposite)
+	}
+
+	/// Get the relation between two elements if they are in the same subset, or `None` if they are not.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::parity_hash_disjoint_set::{Parity, ParityHashDisjointSet};
+	/// #
+	/// let mut set: ParityHashDisjointSet<_> = vec!["a", "b", "c"].into_iter().collect();
+	/// set.union_opposite("a", "b").unwrap();
+	///
+	/// assert_eq!(Ok(Some(Parity::Opposite)), set.relation("a", "b"));
+	/// assert_eq!(Ok(None), set.relation("a", "c"));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn relation<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<Option<Parity>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.set.diff(elem_a, elem_b)
+	}
+
+	/// Determine if two elements are in the same subset.
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn same_subset<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.set.same_subset(elem_a, elem_b)
+	}
+
+	/// Get the number of disjoint subsets in the set.
+	pub fn subset_count(&self) -> usize { self.set.subset_count() }
+
+	/// Get the number of elements in the subset containing the provided element.
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_size<Q>(&mut self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.set.subset_size(elem)
+	}
+}
+
+/// Determine if the graph made of the provided edges is bipartite,
+/// meaning its vertices can be colored with two colors so that the endpoints of every edge differ.
+///
+/// A self-loop makes a graph not bipartite.
+///
+/// # Examples
+/// ```
+/// # use union_find::parity_hash_disjoint_set::is_bipartite;
+/// #
+/// assert!(is_bipartite(vec![(1, 2), (2, 3), (3, 4), (4, 1)]));
+/// assert!(!is_bipartite(vec![(1, 2), (2, 3), (3, 1)]));
+/// ```
+pub fn is_bipartite<T, I>(edges: I) -> bool
+where
+	T: hash::Hash + Eq + Clone,
+	I: IntoIterator<Item = (T, T)>, {
+	let mut set = ParityHashDisjointSet::default();
+
+	edges.into_iter().all(|(a, b)| {
+		let _ = set.insert(a.clone());
+		let _ = set.insert(b.clone());
+		set.union_opposite(&a, &b).is_ok()
+	})
+}
@@ -0,0 +1,112 @@
+use crate::{
+	parity_hash_disjoint_set::{
+		is_bipartite,
+		HashDisjointSetError,
+		Parity,
+		ParityHashDisjointSet,
+		RelationError,
+	},
+	weighted_hash_disjoint_set::Group,
+};
+
+fn letters() -> ParityHashDisjointSet<char> { "abcdefgh".chars().collect() }
+
+#[test]
+fn parity_group_laws() {
+	let parities = [Parity::Same, Parity::Opposite];
+	for a in &parities {
+		assert_eq!(*a, a.combine(&Parity::identity()));
+		assert_eq!(Parity::Same, a.combine(&a.inverse()));
+		for b in &parities {
+			assert_eq!(a.combine(b), b.combine(a));
+		}
+	}
+	assert_eq!(Parity::Same, Parity::Opposite.combine(&Parity::Opposite));
+}
+
+#[test]
+fn create_default() {
+	let set = ParityHashDisjointSet::<u8>::default();
+	assert_eq!(0, set.subset_count());
+}
+
+#[test]
+fn insert_duplicate_err() {
+	let mut set = letters();
+	assert_eq!(Err(HashDisjointSetError::DuplicateElement), set.insert('a'));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn relation_self_is_same() {
+	let mut set = letters();
+	assert_eq!(Ok(Some(Parity::Same)), set.relation(&'c', &'c'));
+}
+
+#[test]
+fn relation_through_chain() {
+	let mut set = letters();
+	set.union_opposite(&'a', &'b').unwrap();
+	set.union_same(&'b', &'c').unwrap();
+	set.union_opposite(&'c', &'d').unwrap();
+	set.union_opposite(&'e', &'f').unwrap();
+	set.union_same(&'f', &'a').unwrap();
+
+	assert_eq!(Ok(Some(Parity::Opposite)), set.relation(&'a', &'c'));
+	assert_eq!(Ok(Some(Parity::Same)), set.relation(&'a', &'d'));
+	assert_eq!(Ok(Some(Parity::Opposite)), set.relation(&'e', &'a'));
+	assert_eq!(Ok(Some(Parity::Same)), set.relation(&'e', &'c'));
+	assert_eq!(Ok(None), set.relation(&'a', &'g'));
+	assert!(set.same_subset(&'d', &'e').unwrap());
+	assert_eq!(6, set.subset_size(&'d').unwrap());
+	assert_eq!(3, set.subset_count());
+}
+
+#[test]
+fn contradictions_leave_set_unchanged() {
+	let mut set = letters();
+	set.union_opposite(&'a', &'b').unwrap();
+	set.union_opposite(&'b', &'c').unwrap();
+
+	assert_eq!(
+		Err(RelationError::Contradiction),
+		set.union_opposite(&'a', &'c')
+	);
+	assert_eq!(
+		Err(RelationError::Contradiction),
+		set.union_same(&'a', &'b')
+	);
+	assert_eq!(
+		Err(RelationError::Contradiction),
+		set.union_opposite(&'a', &'a')
+	);
+	set.union_same(&'c', &'a').unwrap();
+
+	assert_eq!(Ok(Some(Parity::Same)), set.relation(&'a', &'c'));
+	assert_eq!(6, set.subset_count());
+}
+
+#[test]
+fn undefined_err() {
+	let mut set = letters();
+	assert_eq!(
+		Err(RelationError::Element(
+			HashDisjointSetError::ElementNotDefined
+		)),
+		set.union_same(&'a', &'z')
+	);
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.relation(&'z', &'a')
+	);
+}
+
+#[test]
+fn bipartite_graphs() {
+	assert!(is_bipartite(Vec::<(u8, u8)>::new()));
+	assert!(is_bipartite(vec![(0, 1), (2, 3), (1, 2), (3, 0)]));
+	assert!(is_bipartite((0..10).map(|i| (i, i + 1))));
+	assert!(!is_bipartite((0..9).map(|i| (i, (i + 1) % 9))));
+	assert!(!is_bipartite(vec![("a", "a")]));
+	assert!(!is_bipartite(vec![(0, 1), (5, 6), (6, 7), (7, 5)]));
+}
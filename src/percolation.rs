@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use crate::disjoint_set::DisjointSet;
+
+/// A square lattice of `width` by `height` sites for site percolation,
+/// where sites are occupied one at a time and neighboring occupied sites form clusters.
+///
+/// Sites are identified by their column and row, and the neighbors of a site are the sites sharing an edge with it.
+/// The lattice percolates when a cluster spans from the top row to the bottom row.
+///
+/// Clusters are kept in a `DisjointSet` as sites are occupied, in the manner of the Hoshen–Kopelman algorithm,
+/// so the size of every cluster and the number of clusters of each size are always available.
+/// Spanning is checked with a second `DisjointSet` that also has a virtual node joined to every occupied site of the top row,
+/// and another joined to every occupied site of the bottom row.
+/// The virtual nodes are kept out of the clusters, since they would join clusters that only share an edge of the lattice.
+///
+/// Providing a site outside of the lattice panics.
+pub struct Lattice {
+	width: usize,
+	height: usize,
+	occupied: Vec<bool>,
+	occupied_count: usize,
+	clusters: DisjointSet,
+	spanning: DisjointSet,
+	histogram: BTreeMap<usize, usize>,
+}
+
+impl Lattice {
+	/// Creates a `Lattice` with `width` by `height` sites, none of which are occupied.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::percolation::Lattice;
+	/// #
+	/// let mut lattice = Lattice::new(4, 3);
+	///
+	/// assert_eq!(0, lattice.cluster_count());
+	/// assert!(!lattice.percolates());
+	/// ```
+	pub fn new(width: usize, height: usize) -> Self {
+		let n = width * height;
+
+		Lattice {
+			width,
+			height,
+			occupied: vec![false; n],
+			occupied_count: 0,
+			clusters: DisjointSet::new(n),
+			spanning: DisjointSet::new(n + 2),
+			histogram: BTreeMap::new(),
+		}
+	}
+
+	/// Get the number of columns of the lattice.
+	pub fn width(&self) -> usize { self.width }
+
+	/// Get the number of rows of the lattice.
+	pub fn height(&self) -> usize { self.height }
+
+	/// Get the number of occupied sites.
+	pub fn occupied_count(&self) -> usize { self.occupied_count }
+
+	/// Determine if a site is occupied.
+	///
+	/// # Panics
+	/// Panics if the site is outside of the lattice.
+	pub fn is_occupied(&self, site: (usize, usize)) -> bool { self.occupied[self.index(site)] }
+
+	/// Occupies a site, joining it to the clusters of its occupied neighbors.
+	/// Returns `true` if the site was not occupied before.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::percolation::Lattice;
+	/// #
+	/// let mut lattice = Lattice::new(3, 3);
+	/// lattice.occupy((1, 0));
+	/// lattice.occupy((1, 2));
+	/// assert!(!lattice.percolates());
+	///
+	/// assert!(lattice.occupy((1, 1)));
+	/// assert!(!lattice.occupy((1, 1)));
+	/// assert!(lattice.percolates());
+	/// assert_eq!(Some(3), lattice.cluster_size((1, 2)));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the site is outside of the lattice.
+	pub fn occupy(&mut self, site: (usize, usize)) -> bool {
+		let i = self.index(site);
+		if self.occupied[i] {
+			return false;
+		}
+
+		self.occupied[i] = true;
+		self.occupied_count += 1;
+
+		let mut size = 1;
+		for neighbor in self.neighbors(site) {
+			if !self.occupied[neighbor] || self.clusters.same_subset(i, neighbor) {
+				continue;
+			}
+
+			let neighbor_size = self.clusters.subset_size(neighbor);
+			self.remove_cluster(neighbor_size);
+			size += neighbor_size;
+
+			self.clusters.union(i, neighbor);
+			self.spanning.union(i, neighbor);
+		}
+		*self.histogram.entry(size).or_insert(0) += 1;
+
+		let (_, y) = site;
+		if y == 0 {
+			self.spanning.union(i, self.top());
+		}
+		if y + 1 == self.height {
+			self.spanning.union(i, self.bottom());
+		}
+
+		true
+	}
+
+	/// Determine if an occupied cluster spans from the top row to the bottom row.
+	pub fn percolates(&mut self) -> bool {
+		let (top, bottom) = (self.top(), self.bottom());
+		self.spanning.same_subset(top, bottom)
+	}
+
+	/// Get the number of sites in the cluster containing a site, or `None` if the site is not occupied.
+	///
+	/// # Panics
+	/// Panics if the site is outside of the lattice.
+	pub fn cluster_size(&mut self, site: (usize, usize)) -> Option<usize> {
+		let i = self.index(site);
+		if self.occupied[i] {
+			Some(self.clusters.subset_size(i))
+		} else {
+			None
+		}
+	}
+
+	/// Get the number of clusters.
+	pub fn cluster_count(&self) -> usize { self.histogram.values().sum() }
+
+	/// Get the number of sites in the largest cluster, or `0` if no site is occupied.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::percolation::Lattice;
+	/// #
+	/// let mut lattice = Lattice::new(4, 1);
+	/// lattice.occupy((0, 0));
+	/// lattice.occupy((2, 0));
+	/// lattice.occupy((3, 0));
+	///
+	/// assert_eq!(2, lattice.largest_cluster_size());
+	/// ```
+	pub fn largest_cluster_size(&self) -> usize {
+		self.histogram.keys().next_back().copied().unwrap_or(0)
+	}
+
+	/// Get the number of clusters of each size, in order of increasing size.
+	/// Sizes without any clusters are left out.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::percolation::Lattice;
+	/// #
+	/// let mut lattice = Lattice::new(4, 1);
+	/// lattice.occupy((0, 0));
+	/// lattice.occupy((2, 0));
+	/// lattice.occupy((3, 0));
+	/// let histogram = lattice.cluster_size_histogram().iter().collect::<Vec<_>>();
+	///
+	/// assert_eq!(vec![(&1, &1), (&2, &1)], histogram);
+	/// ```
+	pub fn cluster_size_histogram(&self) -> &BTreeMap<usize, usize> { &self.histogram }
+
+	fn remove_cluster(&mut self, size: usize) {
+		let count = self
+			.histogram
+			.get_mut(&size)
+			.expect("every cluster is counted");
+		*count -= 1;
+		if *count == 0 {
+			self.histogram.remove(&size);
+		}
+	}
+
+	fn neighbors(&self, (x, y): (usize, usize)) -> Vec<usize> {
+		let mut neighbors = Vec::with_capacity(4);
+		if x > 0 {
+			neighbors.push(y * self.width + x - 1);
+		}
+		if x + 1 < self.width {
+			neighbors.push(y * self.width + x + 1);
+		}
+		if y > 0 {
+			neighbors.push((y - 1) * self.width + x);
+		}
+		if y + 1 < self.height {
+			neighbors.push((y + 1) * self.width + x);
+		}
+		neighbors
+	}
+
+	fn index(&self, (x, y): (usize, usize)) -> usize {
+		assert!(
+			x < self.width && y < self.height,
+			"the site ({}, {}) is outside of the {}x{} lattice",
+			x,
+			y,
+			self.width,
+			self.height
+		);
+		y * self.width + x
+	}
+
+	/// The virtual node joined to the occupied sites of the top row.
+	fn top(&self) -> usize { self.width * self.height }
+
+	/// The virtual node joined to the occupied sites of the bottom row.
+	fn bottom(&self) -> usize { self.width * self.height + 1 }
+}
@@ -0,0 +1,173 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+	grid_labeling::{label_foreground, Connectivity},
+	percolation::Lattice,
+};
+
+/// A xorshift generator, so the random lattices are the same on every run.
+struct XorShift(u64);
+
+impl XorShift {
+	fn next(&mut self) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0
+	}
+
+	fn below(&mut self, n: usize) -> usize { (self.next() % n as u64) as usize }
+}
+
+/// Checks the lattice against labeling the occupied sites from scratch.
+fn assert_matches_labeling(lattice: &mut Lattice) {
+	let (width, height) = (lattice.width(), lattice.height());
+	let labels = label_foreground(width, height, Connectivity::Four, |x, y| {
+		lattice.is_occupied((x, y))
+	});
+
+	let mut histogram = BTreeMap::new();
+	labels
+		.sizes()
+		.iter()
+		.for_each(|&size| *histogram.entry(size).or_insert(0) += 1);
+	assert_eq!(&histogram, lattice.cluster_size_histogram());
+	assert_eq!(labels.component_count(), lattice.cluster_count());
+	assert_eq!(
+		labels.sizes().iter().copied().max().unwrap_or(0),
+		lattice.largest_cluster_size()
+	);
+
+	let top = (0..width)
+		.filter_map(|x| labels.label(x, 0))
+		.collect::<HashSet<_>>();
+	let spans = (0..width)
+		.filter_map(|x| labels.label(x, height - 1))
+		.any(|label| top.contains(&label));
+	assert_eq!(spans, lattice.percolates());
+
+	for y in 0..height {
+		for x in 0..width {
+			let expected = labels.label(x, y).map(|label| labels.sizes()[label]);
+			assert_eq!(expected, lattice.cluster_size((x, y)));
+		}
+	}
+}
+
+#[test]
+fn create_new() {
+	let mut lattice = Lattice::new(5, 4);
+	assert_eq!((5, 4), (lattice.width(), lattice.height()));
+	assert_eq!(0, lattice.occupied_count());
+	assert_eq!(0, lattice.cluster_count());
+	assert_eq!(0, lattice.largest_cluster_size());
+	assert!(lattice.cluster_size_histogram().is_empty());
+	assert!(!lattice.percolates());
+	assert_eq!(None, lattice.cluster_size((2, 2)));
+}
+
+#[test]
+fn empty_lattice_does_not_percolate() {
+	assert!(!Lattice::new(0, 0).percolates());
+	assert!(!Lattice::new(3, 0).percolates());
+}
+
+#[test]
+fn single_row_percolates_with_any_site() {
+	let mut lattice = Lattice::new(3, 1);
+	assert!(!lattice.percolates());
+	lattice.occupy((2, 0));
+	assert!(lattice.percolates());
+}
+
+#[test]
+fn occupy_twice() {
+	let mut lattice = Lattice::new(2, 2);
+	assert!(lattice.occupy((0, 1)));
+	assert!(!lattice.occupy((0, 1)));
+	assert_eq!(1, lattice.occupied_count());
+	assert_eq!(1, lattice.cluster_count());
+}
+
+#[test]
+fn clusters_touching_top_stay_separate() {
+	let mut lattice = Lattice::new(5, 3);
+	lattice.occupy((0, 0));
+	lattice.occupy((4, 0));
+	lattice.occupy((4, 1));
+
+	assert_eq!(2, lattice.cluster_count());
+	assert_eq!(Some(1), lattice.cluster_size((0, 0)));
+	assert_eq!(Some(2), lattice.cluster_size((4, 0)));
+	assert!(!lattice.percolates());
+}
+
+#[test]
+fn merging_clusters_updates_histogram() {
+	let mut lattice = Lattice::new(3, 3);
+	lattice.occupy((0, 1));
+	lattice.occupy((2, 1));
+	lattice.occupy((1, 0));
+	lattice.occupy((1, 2));
+	assert_eq!(Some(&4), lattice.cluster_size_histogram().get(&1));
+
+	lattice.occupy((1, 1));
+	assert_eq!(1, lattice.cluster_count());
+	assert_eq!(5, lattice.largest_cluster_size());
+	assert_eq!(
+		vec![(&5, &1)],
+		lattice.cluster_size_histogram().iter().collect::<Vec<_>>()
+	);
+	assert!(lattice.percolates());
+}
+
+#[test]
+fn seeded_random_occupation_matches_labeling() {
+	for &seed in &[1, 42, 0x9e37_79b9, 2024] {
+		let mut rng = XorShift(seed);
+		let (width, height) = (6 + rng.below(10), 4 + rng.below(10));
+		let mut lattice = Lattice::new(width, height);
+		let mut percolated = false;
+
+		for step in 0..width * height {
+			let site = (rng.below(width), rng.below(height));
+			lattice.occupy(site);
+
+			// once a spanning cluster exists, occupying more sites cannot remove it
+			let percolates = lattice.percolates();
+			assert!(percolates || !percolated);
+			percolated = percolates;
+
+			if step % 7 == 0 {
+				assert_matches_labeling(&mut lattice);
+			}
+		}
+
+		assert_matches_labeling(&mut lattice);
+	}
+}
+
+#[test]
+fn fully_occupied_lattice() {
+	let mut rng = XorShift(7);
+	let mut lattice = Lattice::new(20, 15);
+	let mut sites = (0..20)
+		.flat_map(|x| (0..15).map(move |y| (x, y)))
+		.collect::<Vec<_>>();
+	for i in (1..sites.len()).rev() {
+		sites.swap(i, rng.below(i + 1));
+	}
+
+	sites.into_iter().for_each(|site| {
+		lattice.occupy(site);
+	});
+
+	assert!(lattice.percolates());
+	assert_eq!(300, lattice.occupied_count());
+	assert_eq!(300, lattice.largest_cluster_size());
+	assert_eq!(1, lattice.cluster_count());
+}
+
+#[test]
+#[should_panic]
+fn occupy_outside_lattice() { Lattice::new(2, 2).occupy((0, 2)); }
@@ -0,0 +1,296 @@
+use std::{ops::Range, sync::Arc};
+
+/// A persistent disjoint set over the elements `0..n`.
+///
+/// Every version of the set is an immutable handle:
+/// `union(..)` returns a new handle and leaves the old one unchanged,
+/// so the partition can be branched cheaply and old versions can still be queried.
+/// The versions share structure, so a union only allocates `O(log n)` new nodes,
+/// and handles are cheap to clone and can be sent between threads.
+///
+/// The parents are kept in a persistent array, which is a balanced binary tree
+/// where an update copies the path to the changed leaf.
+/// Each step of `find(..)` takes `O(log n)` time,
+/// and the union operation is done by size so that there are `O(log n)` steps.
+/// Paths are not compressed, since that would require creating a new version.
+///
+/// As with `DisjointSet`, providing an element outside of `0..len()` panics.
+#[derive(Clone, Default)]
+pub struct PersistentDisjointSet {
+	set: PersistentArray<Entry>,
+	subset_count: usize,
+}
+
+impl PersistentDisjointSet {
+	/// Creates a `PersistentDisjointSet` with the elements `0..n`, each in its own subset.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::persistent_disjoint_set::PersistentDisjointSet;
+	/// #
+	/// let set = PersistentDisjointSet::new(5);
+	///
+	/// assert_eq!(5, set.len());
+	/// assert_eq!(5, set.subset_count());
+	/// ```
+	pub fn new(n: usize) -> Self {
+		PersistentDisjointSet {
+			set: PersistentArray::new(n, |i| Entry { parent: i, size: 1 }),
+			subset_count: n,
+		}
+	}
+
+	/// Get the number of elements in the set.
+	pub fn len(&self) -> usize { self.set.len }
+
+	/// Returns `true` if the set has no elements.
+	pub fn is_empty(&self) -> bool { self.set.len == 0 }
+
+	/// Get a new version of the set where the subsets containing each element are combined.
+	/// The set this is called on is not modified.
+	///
+	/// If the elements are already part of the same subset, the new version is equal to this one.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::persistent_disjoint_set::PersistentDisjointSet;
+	/// #
+	/// let base = PersistentDisjointSet::new(4).union(0, 1);
+	/// let left = base.union(1, 2);
+	/// let right = base.union(2, 3);
+	///
+	/// assert!(left.same_subset(0, 2));
+	/// assert!(!right.same_subset(0, 2));
+	/// assert!(!base.same_subset(1, 2));
+	/// assert_eq!(3, base.subset_count());
+	/// ```
+	///
+	/// # Panics
+	/// Panics if either element is not less than `len()`.
+	pub fn union(&self, elem_a: usize, elem_b: usize) -> Self {
+		let root_a = self.find(elem_a);
+		let root_b = self.find(elem_b);
+
+		if root_a == root_b {
+			return self.clone();
+		}
+
+		let entry_a = *self.set.get(root_a);
+		let entry_b = *self.set.get(root_b);
+		let (root, child) = if entry_a.size < entry_b.size {
+			(root_b, root_a)
+		} else {
+			(root_a, root_b)
+		};
+
+		let size = entry_a.size + entry_b.size;
+		let set = self
+			.set
+			.set(
+				child,
+				Entry {
+					parent: root,
+					size: self.set.get(child).size,
+				},
+			)
+			.set(root, Entry { parent: root, size });
+
+		PersistentDisjointSet {
+			set,
+			subset_count: self.subset_count - 1,
+		}
+	}
+
+	/// Identify the subset of an element in this version by returning the element that represents it.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::persistent_disjoint_set::PersistentDisjointSet;
+	/// #
+	/// let set = PersistentDisjointSet::new(5).union(3, 4);
+	///
+	/// assert_eq!(set.find(3), set.find(4));
+	/// assert_ne!(set.find(3), set.find(2));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn find(&self, elem: usize) -> usize {
+		let mut elem = elem;
+		loop {
+			let parent = self.set.get(elem).parent;
+			if parent == elem {
+				return elem;
+			}
+			elem = parent;
+		}
+	}
+
+	/// Determine if two elements are in the same subset in this version.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::persistent_disjoint_set::PersistentDisjointSet;
+	/// #
+	/// let old = PersistentDisjointSet::new(5);
+	/// let new = old.union(3, 4);
+	///
+	/// assert!(new.same_subset(4, 3));
+	/// assert!(!old.same_subset(4, 3));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if either element is not less than `len()`.
+	pub fn same_subset(&self, elem_a: usize, elem_b: usize) -> bool {
+		self.find(elem_a) == self.find(elem_b)
+	}
+
+	/// Get the number of disjoint subsets in this version.
+	pub fn subset_count(&self) -> usize { self.subset_count }
+
+	/// Get the number of elements in the subset containing the provided element in this version.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::persistent_disjoint_set::PersistentDisjointSet;
+	/// #
+	/// let set = PersistentDisjointSet::new(5).union(3, 4);
+	///
+	/// assert_eq!(2, set.subset_size(3));
+	/// assert_eq!(1, set.subset_size(0));
+	/// ```
+	///
+	/// # Panics
+	/// Panics if the element is not less than `len()`.
+	pub fn subset_size(&self, elem: usize) -> usize { self.set.get(self.find(elem)).size }
+}
+
+/// The parent of an element, and the size of its subset if it is a root.
+#[derive(Clone, Copy)]
+struct Entry {
+	parent: usize,
+	size: usize,
+}
+
+/// An array of `len` values kept in the leaves of a balanced binary tree.
+///
+/// The leaf for an index is found by halving the range of indices at each branch.
+/// `set(..)` copies the nodes on the path to the leaf and shares every other node with the original array.
+struct PersistentArray<V> {
+	root: Option<Arc<Node<V>>>,
+	len: usize,
+}
+
+enum Node<V> {
+	Leaf(V),
+	Branch(Arc<Node<V>>, Arc<Node<V>>),
+}
+
+impl<V> Clone for PersistentArray<V> {
+	fn clone(&self) -> Self {
+		PersistentArray {
+			root: self.root.clone(),
+			len: self.len,
+		}
+	}
+}
+
+impl<V> Default for PersistentArray<V> {
+	fn default() -> Self { PersistentArray { root: None, len: 0 } }
+}
+
+impl<V> PersistentArray<V> {
+	fn new<F>(len: usize, mut value: F) -> Self
+	where F: FnMut(usize) -> V {
+		fn build<V, F>(range: Range<usize>, value: &mut F) -> Arc<Node<V>>
+		where F: FnMut(usize) -> V {
+			if range.len() == 1 {
+				return Arc::new(Node::Leaf(value(range.start)));
+			}
+
+			let mid = midpoint(&range);
+			let left = build(range.start..mid, value);
+			let right = build(mid..range.end, value);
+			Arc::new(Node::Branch(left, right))
+		}
+
+		PersistentArray {
+			root: if len == 0 {
+				None
+			} else {
+				Some(build(0..len, &mut value))
+			},
+			len,
+		}
+	}
+
+	fn get(&self, index: usize) -> &V {
+		self.check(index);
+
+		let mut node = self.root.as_ref().expect("the array is not empty");
+		let mut range = 0..self.len;
+		loop {
+			match node.as_ref() {
+				Node::Leaf(value) => return value,
+				Node::Branch(left, right) => {
+					let mid = midpoint(&range);
+					if index < mid {
+						node = left;
+						range.end = mid;
+					} else {
+						node = right;
+						range.start = mid;
+					}
+				},
+			}
+		}
+	}
+
+	fn set(&self, index: usize, value: V) -> Self {
+		fn copy_path<V>(
+			node: &Arc<Node<V>>,
+			range: Range<usize>,
+			index: usize,
+			value: V,
+		) -> Arc<Node<V>> {
+			match node.as_ref() {
+				Node::Leaf(_) => Arc::new(Node::Leaf(value)),
+				Node::Branch(left, right) => {
+					let mid = midpoint(&range);
+					Arc::new(
+						if index < mid {
+							Node::Branch(
+								copy_path(left, range.start..mid, index, value),
+								right.clone(),
+							)
+						} else {
+							Node::Branch(
+								left.clone(),
+								copy_path(right, mid..range.end, index, value),
+							)
+						},
+					)
+				},
+			}
+		}
+
+		self.check(index);
+
+		let root = self.root.as_ref().expect("the array is not empty");
+		PersistentArray {
+			root: Some(copy_path(root, 0..self.len, index, value)),
+			len: self.len,
+		}
+	}
+
+	fn check(&self, index: usize) {
+		assert!(
+			index < self.len,
+			"index out of bounds: the len is {} but the index is {}",
+			self.len,
+			index
+		);
+	}
+}
+
+fn midpoint(range: &Range<usize>) -> usize { range.start + range.len() / 2 }
@@ -0,0 +1,112 @@
+use crate::{disjoint_set::DisjointSet, persistent_disjoint_set::PersistentDisjointSet};
+
+#[test]
+fn create_new() {
+	let set = PersistentDisjointSet::new(10);
+	assert_eq!(10, set.len());
+	assert_eq!(10, set.subset_count());
+	assert!((0..10).all(|i| set.find(i) == i));
+}
+
+#[test]
+fn create_default() {
+	let set = PersistentDisjointSet::default();
+	assert!(set.is_empty());
+	assert_eq!(0, set.subset_count());
+}
+
+#[test]
+fn union_keeps_old_versions() {
+	let v0 = PersistentDisjointSet::new(6);
+	let v1 = v0.union(0, 1);
+	let v2 = v1.union(2, 3);
+	let v3 = v2.union(1, 3);
+
+	assert_eq!(6, v0.subset_count());
+	assert_eq!(5, v1.subset_count());
+	assert_eq!(4, v2.subset_count());
+	assert_eq!(3, v3.subset_count());
+
+	assert!(!v0.same_subset(0, 1));
+	assert!(v1.same_subset(0, 1));
+	assert!(!v1.same_subset(2, 3));
+	assert!(!v2.same_subset(0, 3));
+	assert!(v3.same_subset(0, 3));
+
+	assert_eq!(1, v0.subset_size(3));
+	assert_eq!(2, v2.subset_size(3));
+	assert_eq!(4, v3.subset_size(3));
+}
+
+#[test]
+fn union_same_subset() {
+	let set = PersistentDisjointSet::new(3).union(0, 1);
+	let same = set.union(1, 0);
+
+	assert_eq!(2, same.subset_count());
+	assert_eq!(set.find(0), same.find(1));
+}
+
+#[test]
+fn branches_are_independent() {
+	let base = PersistentDisjointSet::new(5).union(0, 1);
+	let left = base.union(1, 2);
+	let right = base.union(3, 4).union(4, 0);
+
+	assert!(left.same_subset(0, 2));
+	assert!(!left.same_subset(0, 3));
+	assert!(right.same_subset(1, 3));
+	assert!(!right.same_subset(1, 2));
+	assert_eq!(3, left.subset_size(0));
+	assert_eq!(4, right.subset_size(0));
+}
+
+#[test]
+fn matches_disjoint_set() {
+	let n = 200;
+	let mut expected = DisjointSet::new(n);
+	let mut versions = vec![PersistentDisjointSet::new(n)];
+
+	for i in 0..n {
+		let (a, b) = ((i * 37) % n, (i * 91 + 7) % n);
+		expected.union(a, b);
+		let next = versions.last().unwrap().union(a, b);
+		versions.push(next);
+
+		let current = versions.last().unwrap();
+		assert_eq!(expected.subset_count(), current.subset_count());
+		assert_eq!(expected.subset_size(a), current.subset_size(b));
+	}
+
+	let last = versions.last().unwrap();
+	for a in 0..n {
+		for b in (0..n).step_by(13) {
+			assert_eq!(expected.same_subset(a, b), last.same_subset(a, b));
+		}
+	}
+
+	// the first version is unaffected by every union after it
+	assert!((0..n).all(|i| versions[0].find(i) == i));
+}
+
+#[test]
+fn versions_can_be_shared_between_threads() {
+	let set = PersistentDisjointSet::new(4).union(0, 1);
+	let branch = std::thread::spawn({
+		let set = set.clone();
+		move || set.union(2, 3)
+	})
+	.join()
+	.unwrap();
+
+	assert!(branch.same_subset(2, 3));
+	assert!(!set.same_subset(2, 3));
+}
+
+#[test]
+#[should_panic]
+fn find_out_of_bounds() { PersistentDisjointSet::new(3).find(3); }
+
+#[test]
+#[should_panic]
+fn union_out_of_bounds() { PersistentDisjointSet::default().union(0, 0); }
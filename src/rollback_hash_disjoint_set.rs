@@ -0,0 +1,436 @@
+use std::{
+	collections::{hash_map::Entry, HashMap, HashSet},
+	fmt,
+	fmt::Debug,
+	hash,
+	iter,
+	marker::PhantomData,
+	result,
+};
+
+pub use crate::error::{HashDisjointSetError, InvalidCheckpoint};
+use crate::{
+	next_set_id,
+	strategy::{BySize, NoCompression},
+	unit::{self, Unit},
+	ReadonlyUnionFind,
+	SubsetTicket,
+	UnionFind,
+};
+
+type Result<T> = result::Result<T, HashDisjointSetError>;
+
+/// A disjoint set whose modifications can be undone by rolling back to a `Checkpoint`.
+///
+/// The union operation is done by size, and no path compression is used
+/// so that each modification changes a constant number of entries and can be reverted exactly.
+/// As a result, finding an element takes logarithmic time instead of nearly constant time.
+///
+/// Changes are only recorded while there is a checkpoint to roll back to,
+/// and the record is dropped once the last checkpoint is committed.
+///
+/// Rolling back restores the version used by `SubsetTicket`s,
+/// so tickets created before a checkpoint compare equal to tickets created after rolling back to it.
+/// Tickets created after a checkpoint should not be compared with tickets created after rolling back past it.
+pub struct RollbackHashDisjointSet<'a, T>
+where T: hash::Hash + Eq {
+	ver: usize,
+	map: HashMap<&'a T, usize>,
+	set: Vec<Unit>,
+	subset_count: usize,
+	set_id: usize,
+	history: Vec<Change<'a, T>>,
+	checkpoints: Vec<Mark>,
+	checkpoint_serial: usize,
+}
+
+/// A point in the history of a `RollbackHashDisjointSet` that can be returned to with `rollback_to(..)`.
+///
+/// A checkpoint remains valid until it is committed,
+/// or until the set is rolled back to or commits an earlier checkpoint.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Checkpoint {
+	depth: usize,
+	serial: usize,
+	set_id: usize,
+}
+
+struct Mark {
+	serial: usize,
+	history_len: usize,
+}
+
+enum Change<'a, T> {
+	Insert(&'a T),
+	Union { child: usize, parent: usize },
+}
+
+impl<'a, T: 'a> UnionFind<'a, T> for RollbackHashDisjointSet<'a, T>
+where T: hash::Hash + Eq
+{
+	type UnionFindError = HashDisjointSetError;
+
+	fn union(&mut self, elem_a: &T, elem_b: &T) -> Result<()> {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		// linking by size keeps no nodes
+		let linked = unit::union_with::<BySize, NoCompression>(&mut self.set, &mut [], a_i, b_i);
+		if let Some((parent, child)) = linked {
+			self.record(Change::Union { child, parent });
+
+			self.subset_count -= 1;
+			self.ver += 1;
+		}
+
+		Ok(())
+	}
+
+	fn find(&mut self, elem: &T) -> Result<SubsetTicket<T>> {
+		let i = self.index(elem)?;
+
+		Ok(SubsetTicket {
+			ver: self.ver,
+			id: self.root(i),
+			set_id: self.set_id,
+			phantom: PhantomData,
+		})
+	}
+
+	fn subset_containing(&mut self, elem: &'a T) -> Result<HashSet<&'a T>> {
+		let i = self.index(elem)?;
+		let root = self.root(i);
+
+		Ok(self
+			.map
+			.iter()
+			.filter(|(_, &i)| root == self.root(i))
+			.map(|(&elem, _)| elem)
+			.collect())
+	}
+
+	fn all_subsets(&mut self) -> Vec<HashSet<&'a T>> {
+		let mut subset_map = HashMap::with_capacity(self.subset_count);
+		let mut subsets = Vec::with_capacity(self.subset_count);
+
+		self.map.iter().for_each(|(&elem, &i)| {
+			let entry = subset_map.entry(self.root(i)).or_insert_with(|| {
+				subsets.push(HashSet::new());
+				subsets.len() - 1
+			});
+			subsets[*entry].insert(elem);
+		});
+
+		subsets
+	}
+
+	fn same_subset(&mut self, elem_a: &T, elem_b: &T) -> Result<bool> {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		Ok(self.root(a_i) == self.root(b_i))
+	}
+
+	fn subset_count(&self) -> usize { self.subset_count }
+
+	fn subset_size(&mut self, elem: &T) -> Result<usize> {
+		let i = self.index(elem)?;
+		Ok(self.set[self.root(i)].size)
+	}
+}
+
+impl<'a, T: 'a> ReadonlyUnionFind<'a, T> for RollbackHashDisjointSet<'a, T>
+where T: hash::Hash + Eq
+{
+	fn find_readonly(&self, elem: &T) -> Result<SubsetTicket<T>> {
+		let i = self.index(elem)?;
+
+		Ok(SubsetTicket {
+			ver: self.ver,
+			id: self.root(i),
+			set_id: self.set_id,
+			phantom: PhantomData,
+		})
+	}
+
+	fn same_subset_readonly(&self, elem_a: &T, elem_b: &T) -> Result<bool> {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		Ok(self.root(a_i) == self.root(b_i))
+	}
+
+	fn subset_size_readonly(&self, elem: &T) -> Result<usize> {
+		let i = self.index(elem)?;
+		Ok(self.set[self.root(i)].size)
+	}
+}
+
+impl<T> Default for RollbackHashDisjointSet<'_, T>
+where T: hash::Hash + Eq
+{
+	fn default() -> Self {
+		RollbackHashDisjointSet {
+			ver: 0,
+			map: HashMap::new(),
+			set: Vec::new(),
+			subset_count: 0,
+			set_id: next_set_id(),
+			history: Vec::new(),
+			checkpoints: Vec::new(),
+			checkpoint_serial: 0,
+		}
+	}
+}
+
+impl<'a, T> iter::FromIterator<&'a T> for RollbackHashDisjointSet<'a, T>
+where T: hash::Hash + Eq
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = &'a T> {
+		let mut map = HashMap::new();
+		let mut set = Vec::new();
+
+		iter.into_iter().for_each(|elem| {
+			map.entry(elem).or_insert_with(|| {
+				let len = set.len();
+				set.push(Unit::new(len));
+				len
+			});
+		});
+
+		RollbackHashDisjointSet {
+			ver: 0,
+			set,
+			subset_count: map.len(),
+			map,
+			set_id: next_set_id(),
+			history: Vec::new(),
+			checkpoints: Vec::new(),
+			checkpoint_serial: 0,
+		}
+	}
+}
+
+impl<'a, T> RollbackHashDisjointSet<'a, T>
+where T: hash::Hash + Eq
+{
+	/// Adds an element to the `RollbackHashDisjointSet`.
+	/// The added element is considered part of a new disjoint subset
+	/// containing only that element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::rollback_hash_disjoint_set::RollbackHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	/// set.insert(&b'Q').unwrap();
+	///
+	/// assert_eq!(10, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is already in the set.
+	/// ```
+	/// # use union_find::rollback_hash_disjoint_set::{RollbackHashDisjointSet, HashDisjointSetError};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	/// let result = set.insert(&b'T').unwrap_err();
+	///
+	/// assert_eq!(result, HashDisjointSetError::DuplicateElement);
+	/// ```
+	pub fn insert(&mut self, elem: &'a T) -> Result<()> {
+		let set = &mut self.set;
+
+		if let Entry::Vacant(entry) = self.map.entry(elem) {
+			entry.insert(set.len());
+			Ok(())
+		} else {
+			Err(HashDisjointSetError::DuplicateElement)
+		}?;
+
+		set.push(Unit::new(set.len()));
+		self.record(Change::Insert(elem));
+		self.subset_count += 1;
+		self.ver += 1;
+
+		Ok(())
+	}
+
+	/// Records the current state of the set so that it can be restored with `rollback_to(..)`.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::rollback_hash_disjoint_set::RollbackHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	/// let checkpoint = set.checkpoint();
+	/// set.union(&b'T', &b't').unwrap();
+	/// set.rollback_to(checkpoint).unwrap();
+	///
+	/// assert!(!set.same_subset(&b'T', &b't').unwrap());
+	/// ```
+	pub fn checkpoint(&mut self) -> Checkpoint {
+		let serial = self.checkpoint_serial;
+		self.checkpoint_serial += 1;
+
+		self.checkpoints.push(Mark {
+			serial,
+			history_len: self.history.len(),
+		});
+
+		Checkpoint {
+			depth: self.checkpoints.len() - 1,
+			serial,
+			set_id: self.set_id,
+		}
+	}
+
+	/// Undoes every insertion and union made since the checkpoint was taken.
+	///
+	/// The subset count, subset sizes and the version used by `SubsetTicket`s are restored exactly.
+	/// The checkpoint stays valid, so it can be rolled back to again,
+	/// but every checkpoint taken after it is discarded.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::rollback_hash_disjoint_set::RollbackHashDisjointSet;
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	/// set.union(&b'T', &b'h').unwrap();
+	/// let ticket = set.find(&b'T').unwrap();
+	/// let checkpoint = set.checkpoint();
+	///
+	/// set.insert(&b'Q').unwrap();
+	/// set.union(&b'Q', &b'T').unwrap();
+	/// assert_eq!(3, set.subset_size(&b'h').unwrap());
+	///
+	/// set.rollback_to(checkpoint).unwrap();
+	/// assert_eq!(2, set.subset_size(&b'h').unwrap());
+	/// assert_eq!(8, set.subset_count());
+	/// assert_eq!(ticket, set.find(&b'T').unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the checkpoint was taken from a different set,
+	/// or if it was discarded by rolling back to an earlier checkpoint.
+	/// ```
+	/// # use union_find::rollback_hash_disjoint_set::{InvalidCheckpoint, RollbackHashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// #
+	/// let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	/// let earlier = set.checkpoint();
+	/// let later = set.checkpoint();
+	/// set.rollback_to(earlier).unwrap();
+	///
+	/// let result = set.rollback_to(later).unwrap_err();
+	///
+	/// assert_eq!(result, InvalidCheckpoint);
+	/// ```
+	pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> result::Result<(), InvalidCheckpoint> {
+		let history_len = self.mark(checkpoint)?.history_len;
+
+		self.checkpoints.truncate(checkpoint.depth + 1);
+
+		for change in self.history.drain(history_len..).rev() {
+			match change {
+				Change::Insert(elem) => {
+					self.map.remove(elem);
+					self.set.pop();
+					self.subset_count -= 1;
+				},
+				Change::Union { child, parent } => {
+					self.set[child].parent = child;
+					self.set[parent].size -= self.set[child].size;
+					self.subset_count += 1;
+				},
+			}
+			self.ver -= 1;
+		}
+
+		Ok(())
+	}
+
+	/// Keeps every change made since the checkpoint was taken,
+	/// and discards the checkpoint along with every checkpoint taken after it.
+	///
+	/// Once no checkpoints remain, the recorded changes are dropped and no more changes are recorded
+	/// until the next checkpoint is taken.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::rollback_hash_disjoint_set::{InvalidCheckpoint, RollbackHashDisjointSet};
+	/// # use std::iter::FromIterator;
+	/// # use union_find::UnionFind;
+	/// #
+	/// let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	/// let checkpoint = set.checkpoint();
+	/// set.union(&b'T', &b't').unwrap();
+	/// set.commit(checkpoint).unwrap();
+	///
+	/// assert!(set.same_subset(&b'T', &b't').unwrap());
+	/// assert_eq!(Err(InvalidCheckpoint), set.rollback_to(checkpoint));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the checkpoint was taken from a different set,
+	/// or if it was discarded by rolling back to or committing an earlier checkpoint.
+	pub fn commit(&mut self, checkpoint: Checkpoint) -> result::Result<(), InvalidCheckpoint> {
+		self.mark(checkpoint)?;
+
+		self.checkpoints.truncate(checkpoint.depth);
+		if self.checkpoints.is_empty() {
+			self.history.clear();
+		}
+
+		Ok(())
+	}
+
+	/// The number of recorded changes, so tests can check that changes are only kept while needed.
+	#[cfg(test)]
+	pub(crate) fn history_len(&self) -> usize { self.history.len() }
+
+	/// Records a change if there is a checkpoint that could undo it.
+	fn record(&mut self, change: Change<'a, T>) {
+		if !self.checkpoints.is_empty() {
+			self.history.push(change);
+		}
+	}
+
+	/// The mark of a checkpoint that is still valid for this set.
+	fn mark(&self, checkpoint: Checkpoint) -> result::Result<&Mark, InvalidCheckpoint> {
+		match self.checkpoints.get(checkpoint.depth) {
+			Some(mark) if checkpoint.set_id == self.set_id && mark.serial == checkpoint.serial => {
+				Ok(mark)
+			},
+			_ => Err(InvalidCheckpoint),
+		}
+	}
+
+	fn root(&self, elem: usize) -> usize { unit::root(&self.set, elem) }
+
+	fn index(&self, elem: &T) -> Result<usize> {
+		Ok(*self
+			.map
+			.get(&elem)
+			.ok_or(HashDisjointSetError::ElementNotDefined)?)
+	}
+}
+
+impl<'a, T> RollbackHashDisjointSet<'a, T>
+where T: hash::Hash + Eq + Debug
+{
+	/// Pretty prints a `RollbackHashDisjointSet` for debugging purposes.
+	///
+	/// See `HashDisjointSet::fmt(..)` for why the `Debug` trait is not used.
+	pub fn fmt(&mut self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:?}", self.all_subsets())
+	}
+}
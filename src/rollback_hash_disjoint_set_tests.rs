@@ -0,0 +1,242 @@
+use std::iter::FromIterator;
+
+use crate::{
+	rollback_hash_disjoint_set::{
+		HashDisjointSetError,
+		InvalidCheckpoint,
+		RollbackHashDisjointSet,
+	},
+	ReadonlyUnionFind,
+	UnionFind,
+};
+
+#[test]
+fn create_from_iter() { RollbackHashDisjointSet::from_iter(b"This is a test."); }
+
+#[test]
+fn create_default() { RollbackHashDisjointSet::<u8>::default(); }
+
+#[test]
+fn union_subset_count() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+	set.union(&b'h', &b'T').unwrap();
+	assert_eq!(7, set.subset_count());
+	assert_eq!(3, set.subset_size(&b'T').unwrap());
+}
+
+#[test]
+fn union_err() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.union(&b'T', &b'Q')
+	);
+}
+
+#[test]
+fn insert_err() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	assert_eq!(
+		Err(HashDisjointSetError::DuplicateElement),
+		set.insert(&b'T')
+	);
+}
+
+#[test]
+fn subset_containing() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+
+	let subset = set.subset_containing(&b'h').unwrap();
+	assert_eq!(3, subset.len());
+	assert!(b"Thi".iter().all(|elem| subset.contains(elem)));
+	assert_eq!(7, set.all_subsets().len());
+}
+
+#[test]
+fn rollback_union() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let checkpoint = set.checkpoint();
+
+	set.union(&b'h', &b'i').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+	set.rollback_to(checkpoint).unwrap();
+
+	assert_eq!(9, set.subset_count());
+	assert_eq!(1, set.subset_size(&b'i').unwrap());
+	assert!(!set.same_subset(&b'h', &b'T').unwrap());
+}
+
+#[test]
+fn rollback_insert() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let checkpoint = set.checkpoint();
+
+	set.insert(&b'Q').unwrap();
+	set.union(&b'Q', &b'T').unwrap();
+	set.rollback_to(checkpoint).unwrap();
+
+	assert_eq!(9, set.subset_count());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.find(&b'Q')
+	);
+	assert_eq!(Ok(()), set.insert(&b'Q'));
+}
+
+#[test]
+fn rollback_nested() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+
+	set.union(&b'h', &b'i').unwrap();
+	let outer = set.checkpoint();
+	set.union(&b'T', &b'i').unwrap();
+	let inner = set.checkpoint();
+	set.union(&b's', &b'i').unwrap();
+
+	set.rollback_to(inner).unwrap();
+	assert_eq!(3, set.subset_size(&b'h').unwrap());
+	assert_eq!(7, set.subset_count());
+
+	set.rollback_to(outer).unwrap();
+	assert_eq!(2, set.subset_size(&b'h').unwrap());
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn rollback_same_checkpoint_twice() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let checkpoint = set.checkpoint();
+
+	set.union(&b'h', &b'i').unwrap();
+	set.rollback_to(checkpoint).unwrap();
+	set.union(&b'T', &b'i').unwrap();
+	set.rollback_to(checkpoint).unwrap();
+
+	assert_eq!(9, set.subset_count());
+	assert!(!set.same_subset(&b'T', &b'i').unwrap());
+}
+
+#[test]
+fn rollback_no_change_union() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	let checkpoint = set.checkpoint();
+
+	set.union(&b'i', &b'h').unwrap();
+	set.rollback_to(checkpoint).unwrap();
+
+	assert!(set.same_subset(&b'h', &b'i').unwrap());
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn rollback_ver_find() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+
+	let ticket1 = set.find(&b'h').unwrap();
+	let checkpoint = set.checkpoint();
+	set.union(&b'T', &b'i').unwrap();
+	let ticket2 = set.find(&b'h').unwrap();
+	set.rollback_to(checkpoint).unwrap();
+	let ticket3 = set.find(&b'h').unwrap();
+
+	assert_ne!(ticket1, ticket2);
+	assert_eq!(ticket1, ticket3);
+}
+
+#[test]
+fn rollback_discarded_checkpoint_err() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let earlier = set.checkpoint();
+	set.union(&b'h', &b'i').unwrap();
+	let later = set.checkpoint();
+	set.rollback_to(earlier).unwrap();
+	set.union(&b'h', &b'i').unwrap();
+	set.checkpoint();
+
+	assert_eq!(Err(InvalidCheckpoint), set.rollback_to(later));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn rollback_other_set_err() {
+	let mut set1 = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let mut set2 = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let checkpoint = set1.checkpoint();
+	set2.checkpoint();
+
+	assert_eq!(Err(InvalidCheckpoint), set2.rollback_to(checkpoint));
+}
+
+#[test]
+fn readonly_queries() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'h', &b'i').unwrap();
+	set.union(&b'T', &b'i').unwrap();
+
+	assert_eq!(set.find(&b'h').unwrap(), set.find_readonly(&b'T').unwrap());
+	assert!(set.same_subset_readonly(&b'T', &b'h').unwrap());
+	assert_eq!(3, set.subset_size_readonly(&b'i').unwrap());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_size_readonly(&b'Q')
+	);
+}
+
+#[test]
+fn history_only_kept_with_checkpoint() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b't').unwrap();
+	set.insert(&b'Q').unwrap();
+	assert_eq!(0, set.history_len());
+
+	let checkpoint = set.checkpoint();
+	set.union(&b'h', &b'i').unwrap();
+	set.insert(&b'R').unwrap();
+	assert_eq!(2, set.history_len());
+
+	set.rollback_to(checkpoint).unwrap();
+	assert_eq!(0, set.history_len());
+	assert_eq!(9, set.subset_count());
+}
+
+#[test]
+fn commit_keeps_changes() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let outer = set.checkpoint();
+	set.union(&b'T', &b't').unwrap();
+	let inner = set.checkpoint();
+	set.union(&b'h', &b'i').unwrap();
+
+	set.commit(inner).unwrap();
+	assert_eq!(2, set.history_len());
+	assert_eq!(Err(InvalidCheckpoint), set.rollback_to(inner));
+
+	set.commit(outer).unwrap();
+	assert_eq!(0, set.history_len());
+	assert!(set.same_subset(&b'T', &b't').unwrap());
+	assert!(set.same_subset(&b'h', &b'i').unwrap());
+	assert_eq!(7, set.subset_count());
+
+	set.union(&b'a', &b'e').unwrap();
+	assert_eq!(0, set.history_len());
+}
+
+#[test]
+fn commit_then_rollback_outer() {
+	let mut set = RollbackHashDisjointSet::from_iter(b"This is a test.");
+	let outer = set.checkpoint();
+	set.union(&b'T', &b't').unwrap();
+	let inner = set.checkpoint();
+	set.union(&b'h', &b'i').unwrap();
+	set.commit(inner).unwrap();
+
+	set.rollback_to(outer).unwrap();
+	assert_eq!(9, set.subset_count());
+	assert_eq!(Err(InvalidCheckpoint), set.commit(inner));
+}
@@ -0,0 +1,98 @@
+use std::{collections::HashSet, iter::FromIterator};
+
+use crate::{
+	disjoint_set::DisjointSet,
+	hash_disjoint_set::HashDisjointSet,
+	owned_hash_disjoint_set::OwnedHashDisjointSet,
+	UnionFind,
+};
+
+fn sorted_subsets(set: &mut OwnedHashDisjointSet<String>) -> Vec<Vec<String>> {
+	let mut subsets = set
+		.all_subsets()
+		.into_iter()
+		.map(|subset| {
+			let mut subset = subset.into_iter().cloned().collect::<Vec<_>>();
+			subset.sort();
+			subset
+		})
+		.collect::<Vec<_>>();
+	subsets.sort();
+	subsets
+}
+
+#[test]
+fn disjoint_set_round_trip() {
+	let mut set = DisjointSet::new(8);
+	set.union(0, 5);
+	set.union(5, 7);
+	set.union(2, 3);
+
+	let json = serde_json::to_string(&set).unwrap();
+	let mut actual: DisjointSet = serde_json::from_str(&json).unwrap();
+
+	assert_eq!(set.subset_count(), actual.subset_count());
+	assert_eq!(set.all_subsets(), actual.all_subsets());
+	assert_eq!(3, actual.subset_size(7));
+}
+
+#[test]
+fn disjoint_set_empty_round_trip() {
+	let json = serde_json::to_string(&DisjointSet::default()).unwrap();
+	let actual: DisjointSet = serde_json::from_str(&json).unwrap();
+
+	assert_eq!("[]", json);
+	assert!(actual.is_empty());
+}
+
+#[test]
+fn disjoint_set_representatives() {
+	let actual: DisjointSet = serde_json::from_str("[1, 1, 2, 0]").unwrap();
+
+	assert_eq!(2, actual.subset_count());
+	assert!(actual.same_subset_readonly(3, 1));
+}
+
+#[test]
+fn disjoint_set_out_of_bounds_err() {
+	assert!(serde_json::from_str::<DisjointSet>("[0, 1, 3]").is_err());
+}
+
+#[test]
+fn owned_round_trip() {
+	let mut set: OwnedHashDisjointSet<String> =
+		"a b c d e f".split(' ').map(String::from).collect();
+	set.union("a", "c").unwrap();
+	set.union("e", "c").unwrap();
+	set.union("b", "d").unwrap();
+
+	let json = serde_json::to_string(&set).unwrap();
+	let mut actual: OwnedHashDisjointSet<String> = serde_json::from_str(&json).unwrap();
+
+	assert_eq!(set.subset_count(), actual.subset_count());
+	assert_eq!(sorted_subsets(&mut set), sorted_subsets(&mut actual));
+	assert_eq!(3, actual.subset_size("a").unwrap());
+}
+
+#[test]
+fn owned_duplicate_err() {
+	let result = serde_json::from_str::<OwnedHashDisjointSet<u8>>("[[1, 2], [2]]");
+	assert!(result.is_err());
+}
+
+#[test]
+fn hash_disjoint_set_into_owned() {
+	let mut set = HashDisjointSet::from_iter(b"This is a test.");
+	set.union(&b'T', &b't').unwrap();
+	set.union(&b'e', &b't').unwrap();
+
+	let json = serde_json::to_string(&set).unwrap();
+	let mut actual: OwnedHashDisjointSet<u8> = serde_json::from_str(&json).unwrap();
+
+	assert_eq!(set.subset_count(), actual.subset_count());
+	assert_eq!(
+		HashSet::from_iter(b"Tte"),
+		actual.subset_containing(&b'e').unwrap()
+	);
+	assert!(!actual.same_subset(&b'T', &b'h').unwrap());
+}
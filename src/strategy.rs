@@ -0,0 +1,190 @@
+// The strategies work on the crate-private forest. The sealed traits cannot be named outside of the crate,
+// so their signatures do not expose it.
+#[allow(private_interfaces)]
+mod sealed {
+	use std::mem;
+
+	use super::{
+		AlwaysLeft,
+		ByRandomIndex,
+		ByRank,
+		BySize,
+		FullCompression,
+		NoCompression,
+		PathHalving,
+		PathSplitting,
+	};
+	use crate::unit::{self, Unit};
+
+	pub trait Link {
+		/// What the strategy keeps for each element, such as its rank.
+		/// Strategies that only need the forest use `()`, which takes no space.
+		type Node: Copy;
+
+		/// The node of an element whose tree is at most `height` levels deep.
+		fn node(height: usize) -> Self::Node;
+
+		/// Orders two different roots as the root of the combined subset
+		/// followed by the root that will be linked below it.
+		fn order(
+			set: &[Unit],
+			nodes: &mut [Self::Node],
+			root_a: usize,
+			root_b: usize,
+		) -> (usize, usize);
+	}
+
+	pub trait Compress {
+		/// Finds the root of `elem`, shortening the path along the way.
+		fn find(set: &mut [Unit], elem: usize) -> usize;
+	}
+
+	impl Link for BySize {
+		type Node = ();
+
+		fn node(_: usize) {}
+
+		fn order(set: &[Unit], _: &mut [()], root_a: usize, root_b: usize) -> (usize, usize) {
+			if set[root_a].size < set[root_b].size {
+				(root_b, root_a)
+			} else {
+				(root_a, root_b)
+			}
+		}
+	}
+
+	impl Link for ByRank {
+		type Node = usize;
+
+		fn node(height: usize) -> usize { height }
+
+		fn order(_: &[Unit], ranks: &mut [usize], root_a: usize, root_b: usize) -> (usize, usize) {
+			let (mut root_a, mut root_b) = (root_a, root_b);
+
+			if ranks[root_a] < ranks[root_b] {
+				mem::swap(&mut root_a, &mut root_b);
+			} else if ranks[root_a] == ranks[root_b] {
+				ranks[root_a] += 1;
+			}
+
+			(root_a, root_b)
+		}
+	}
+
+	impl Link for ByRandomIndex {
+		type Node = ();
+
+		fn node(_: usize) {}
+
+		fn order(_: &[Unit], _: &mut [()], root_a: usize, root_b: usize) -> (usize, usize) {
+			if unit::priority(root_a) < unit::priority(root_b) {
+				(root_b, root_a)
+			} else {
+				(root_a, root_b)
+			}
+		}
+	}
+
+	impl Link for AlwaysLeft {
+		type Node = ();
+
+		fn node(_: usize) {}
+
+		fn order(_: &[Unit], _: &mut [()], root_a: usize, root_b: usize) -> (usize, usize) {
+			(root_a, root_b)
+		}
+	}
+
+	impl Compress for NoCompression {
+		fn find(set: &mut [Unit], elem: usize) -> usize { unit::root(set, elem) }
+	}
+
+	impl Compress for FullCompression {
+		fn find(set: &mut [Unit], elem: usize) -> usize {
+			let root = unit::root(set, elem);
+
+			let mut elem = elem;
+			while elem != root {
+				elem = mem::replace(&mut set[elem].parent, root);
+			}
+
+			root
+		}
+	}
+
+	impl Compress for PathHalving {
+		fn find(set: &mut [Unit], elem: usize) -> usize {
+			let mut elem = elem;
+			while set[elem].parent != elem {
+				let grandparent = set[set[elem].parent].parent;
+				set[elem].parent = grandparent;
+				elem = grandparent;
+			}
+			elem
+		}
+	}
+
+	impl Compress for PathSplitting {
+		fn find(set: &mut [Unit], elem: usize) -> usize { unit::find(set, elem) }
+	}
+}
+
+/// Decides which root becomes the parent when two subsets are combined.
+///
+/// This trait is sealed. The provided strategies are `BySize`, `ByRank`, `ByRandomIndex` and `AlwaysLeft`.
+pub trait Link: sealed::Link {}
+
+/// Decides how the path from an element to its root is shortened while finding the root.
+///
+/// This trait is sealed. The provided strategies are `NoCompression`, `FullCompression`,
+/// `PathHalving` and `PathSplitting`.
+pub trait Compress: sealed::Compress {}
+
+/// Links the root of the smaller subset below the root of the larger subset.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BySize;
+
+/// Links the root with the lower rank below the root with the higher rank.
+///
+/// The rank is an upper bound on the height of the tree below a root.
+/// It is kept alongside the forest by the sets using this strategy, so other strategies do not pay for it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByRank;
+
+/// Links roots by a fixed pseudo-random priority of their index.
+///
+/// This keeps the trees shallow in expectation without storing any information about the subsets.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByRandomIndex;
+
+/// Always links the root of the second element below the root of the first element.
+///
+/// The trees can become as deep as the number of elements, so this is mostly useful as a baseline.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysLeft;
+
+/// Leaves paths as they are.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoCompression;
+
+/// Points every element on the path directly to the root, using a second pass over the path.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FullCompression;
+
+/// Points every other element on the path to its grandparent.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PathHalving;
+
+/// Points every element on the path to its grandparent.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PathSplitting;
+
+impl Link for BySize {}
+impl Link for ByRank {}
+impl Link for ByRandomIndex {}
+impl Link for AlwaysLeft {}
+
+impl Compress for NoCompression {}
+impl Compress for FullCompression {}
+impl Compress for PathHalving {}
+impl Compress for PathSplitting {}
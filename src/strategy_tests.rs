@@ -0,0 +1,176 @@
+use crate::{
+	strategy::{
+		AlwaysLeft,
+		ByRandomIndex,
+		ByRank,
+		BySize,
+		Compress,
+		FullCompression,
+		Link,
+		NoCompression,
+		PathHalving,
+		PathSplitting,
+	},
+	unit::{self, Unit},
+};
+
+fn units(n: usize) -> Vec<Unit> { (0..n).map(Unit::new).collect() }
+
+/// Builds the path `0 -> 1 -> .. -> n - 1` without compressing it.
+fn path(n: usize) -> Vec<Unit> {
+	let mut set = units(n);
+	for i in 1..n {
+		unit::union_with::<AlwaysLeft, NoCompression>(&mut set, &mut [], i, i - 1);
+	}
+	set
+}
+
+fn parents(set: &[Unit]) -> Vec<usize> { set.iter().map(|unit| unit.parent).collect() }
+
+fn find<C: Compress>(set: &mut [Unit], elem: usize) -> usize { C::find(set, elem) }
+
+fn union<L: Link>(
+	set: &mut [Unit],
+	nodes: &mut [L::Node],
+	a: usize,
+	b: usize,
+) -> Option<(usize, usize)> {
+	unit::union_with::<L, NoCompression>(set, nodes, a, b)
+}
+
+#[test]
+fn no_compression_keeps_path() {
+	let mut set = path(5);
+	assert_eq!(4, find::<NoCompression>(&mut set, 0));
+	assert_eq!(vec![1, 2, 3, 4, 4], parents(&set));
+}
+
+#[test]
+fn full_compression_flattens_path() {
+	let mut set = path(5);
+	assert_eq!(4, find::<FullCompression>(&mut set, 0));
+	assert_eq!(vec![4, 4, 4, 4, 4], parents(&set));
+}
+
+#[test]
+fn path_halving_skips_every_other() {
+	let mut set = path(5);
+	assert_eq!(4, find::<PathHalving>(&mut set, 0));
+	assert_eq!(vec![2, 2, 4, 4, 4], parents(&set));
+}
+
+#[test]
+fn path_splitting_points_to_grandparents() {
+	let mut set = path(5);
+	assert_eq!(4, find::<PathSplitting>(&mut set, 0));
+	assert_eq!(vec![2, 3, 4, 4, 4], parents(&set));
+}
+
+#[test]
+fn always_left_links_second_below_first() {
+	let mut set = units(3);
+	assert_eq!(Some((2, 0)), union::<AlwaysLeft>(&mut set, &mut [], 2, 0));
+	assert_eq!(Some((1, 2)), union::<AlwaysLeft>(&mut set, &mut [], 1, 0));
+	assert_eq!(None, union::<AlwaysLeft>(&mut set, &mut [], 0, 1));
+	assert_eq!(3, set[1].size);
+}
+
+#[test]
+fn by_size_links_smaller_below_larger() {
+	let mut set = units(4);
+	union::<BySize>(&mut set, &mut [], 0, 1);
+	assert_eq!(Some((0, 2)), union::<BySize>(&mut set, &mut [], 2, 1));
+	assert_eq!(Some((0, 3)), union::<BySize>(&mut set, &mut [], 3, 0));
+}
+
+#[test]
+fn by_rank_grows_on_ties() {
+	let mut set = units(4);
+	let mut ranks = vec![0; 4];
+	assert_eq!(Some((0, 1)), union::<ByRank>(&mut set, &mut ranks, 0, 1));
+	assert_eq!(1, ranks[0]);
+	assert_eq!(Some((0, 2)), union::<ByRank>(&mut set, &mut ranks, 2, 0));
+	assert_eq!(1, ranks[0]);
+	assert_eq!(Some((0, 3)), union::<ByRank>(&mut set, &mut ranks, 3, 1));
+	assert_eq!(vec![1, 0, 0, 0], ranks);
+}
+
+#[test]
+fn by_random_index_is_fixed() {
+	let mut set = units(2);
+	let mut reversed = units(2);
+	assert_eq!(
+		union::<ByRandomIndex>(&mut set, &mut [], 0, 1),
+		union::<ByRandomIndex>(&mut reversed, &mut [], 1, 0)
+	);
+}
+
+#[test]
+fn shallow_trees() {
+	let n = 1 << 10;
+	for &deep in &[false, true] {
+		let mut size = units(n);
+		let mut rank = units(n);
+		let mut ranks = vec![0; n];
+		let mut random = units(n);
+		for i in 1..n {
+			let (a, b) = if deep { (i, i - 1) } else { (i - 1, i) };
+			union::<BySize>(&mut size, &mut [], a, b);
+			union::<ByRank>(&mut rank, &mut ranks, a, b);
+			union::<ByRandomIndex>(&mut random, &mut [], a, b);
+		}
+
+		let depth = |set: &[Unit]| {
+			(0..n)
+				.map(|mut i| {
+					let mut depth = 0;
+					while set[i].parent != i {
+						i = set[i].parent;
+						depth += 1;
+					}
+					depth
+				})
+				.max()
+				.unwrap()
+		};
+		assert!(depth(&size) <= 10);
+		assert!(depth(&rank) <= 10);
+		assert!(depth(&random) < n / 4);
+	}
+}
+
+/// Runs the `HashDisjointSet` tests with the provided strategies.
+macro_rules! hash_disjoint_set_tests {
+	($($name:ident: $link:ty, $compress:ty;)*) => {
+		$(
+			#[allow(clippy::bool_assert_comparison, clippy::unnecessary_to_owned)]
+			mod $name {
+				use crate::strategy::*;
+
+				type HashDisjointSet<'a, T> =
+					crate::hash_disjoint_set::StrategyHashDisjointSet<'a, T, $link, $compress>;
+
+				include!("hash_disjoint_set_tests.rs");
+			}
+		)*
+	};
+}
+
+hash_disjoint_set_tests! {
+	size_no_compression: BySize, NoCompression;
+	size_full_compression: BySize, FullCompression;
+	size_path_halving: BySize, PathHalving;
+	size_path_splitting: BySize, PathSplitting;
+	rank_no_compression: ByRank, NoCompression;
+	rank_full_compression: ByRank, FullCompression;
+	rank_path_halving: ByRank, PathHalving;
+	rank_path_splitting: ByRank, PathSplitting;
+	random_index_no_compression: ByRandomIndex, NoCompression;
+	random_index_full_compression: ByRandomIndex, FullCompression;
+	random_index_path_halving: ByRandomIndex, PathHalving;
+	random_index_path_splitting: ByRandomIndex, PathSplitting;
+	always_left_no_compression: AlwaysLeft, NoCompression;
+	always_left_full_compression: AlwaysLeft, FullCompression;
+	always_left_path_halving: AlwaysLeft, PathHalving;
+	always_left_path_splitting: AlwaysLeft, PathSplitting;
+}
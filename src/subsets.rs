@@ -0,0 +1,371 @@
+use std::{collections::HashMap, marker::PhantomData, result};
+
+use crate::{
+	error::StaleTicket,
+	next_set_id,
+	strategy::{Compress, Link},
+	unit::{self, Unit},
+	StableTicket,
+	SubsetTicket,
+};
+
+// The hashed disjoint sets give each element an index and keep the subsets of those indices in `Subsets`,
+// so each set only has to store its elements and map them to their indices.
+
+/// The subsets of the indices `0..len`, with their member lists and the state behind the tickets of a set.
+pub(crate) struct Subsets<L, C>
+where L: Link {
+	ver: usize,
+	set: Vec<Unit>,
+	/// The member lists of the subsets, see `unit::members(..)`.
+	next: Vec<usize>,
+	nodes: Vec<L::Node>,
+	/// The generation of each root, which changes whenever its subset does.
+	generations: Vec<usize>,
+	/// The generation given to the next subset that changes, so a generation is never given twice.
+	next_generation: usize,
+	/// The roots moved to another index by `swap_remove(..)`, by their generation,
+	/// with the index they had when the generation was given and the index they have now.
+	moved: HashMap<usize, (usize, usize)>,
+	subset_count: usize,
+	set_id: usize,
+	removed_ver: usize,
+	strategy: PhantomData<C>,
+}
+
+/// The storage of the elements of a set by index, which the `Members` of its subsets are taken from.
+pub trait Elements<'s> {
+	/// The element returned for an index.
+	type Item;
+
+	/// Get the element at `index`.
+	fn element(&'s self, index: usize) -> Self::Item;
+}
+
+impl<'s, 'a, T> Elements<'s> for [&'a T] {
+	type Item = &'a T;
+
+	fn element(&'s self, index: usize) -> &'a T { self[index] }
+}
+
+/// An iterator over the members of a subset of a `HashDisjointSet` or an `OwnedHashDisjointSet`.
+///
+/// It is created by `members(..)`, `members_by_ticket(..)` and `iter_subsets()`.
+pub struct Members<'s, E>
+where E: ?Sized {
+	elems: &'s E,
+	members: unit::Members<'s>,
+}
+
+impl<E> Clone for Members<'_, E>
+where E: ?Sized
+{
+	fn clone(&self) -> Self {
+		Members {
+			elems: self.elems,
+			members: self.members.clone(),
+		}
+	}
+}
+
+impl<'s, E> Iterator for Members<'s, E>
+where E: Elements<'s> + ?Sized
+{
+	type Item = E::Item;
+
+	fn next(&mut self) -> Option<E::Item> { self.members.next().map(|i| self.elems.element(i)) }
+}
+
+impl<L, C> Subsets<L, C>
+where L: Link
+{
+	/// Creates `len` subsets, each containing only its own index.
+	pub(crate) fn new(len: usize) -> Self {
+		Subsets {
+			ver: 0,
+			set: (0..len).map(Unit::new).collect(),
+			next: (0..len).collect(),
+			nodes: vec![L::node(0); len],
+			generations: (0..len).collect(),
+			next_generation: len,
+			moved: HashMap::new(),
+			subset_count: len,
+			set_id: next_set_id(),
+			removed_ver: 0,
+			strategy: PhantomData,
+		}
+	}
+
+	pub(crate) fn subset_count(&self) -> usize { self.subset_count }
+
+	/// Finds the root of `elem` without modifying the subsets.
+	pub(crate) fn root(&self, elem: usize) -> usize { unit::root(&self.set, elem) }
+
+	/// The number of members of the subset rooted at `root`.
+	pub(crate) fn size(&self, root: usize) -> usize { self.set[root].size }
+
+	/// Iterates over the members of the subset containing `elem`, starting with `elem`,
+	/// as the elements at their indices in `elems`.
+	pub(crate) fn members<'s, E>(&'s self, elems: &'s E, elem: usize) -> Members<'s, E>
+	where E: ?Sized {
+		Members {
+			elems,
+			members: unit::members(&self.next, elem),
+		}
+	}
+
+	/// Iterates over the roots of the subsets.
+	pub(crate) fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+		let set = &self.set;
+		(0..set.len()).filter(move |&i| set[i].parent == i)
+	}
+
+	/// Iterates over the subsets, as the elements at their indices in `elems`.
+	pub(crate) fn iter_subsets<'s, E>(
+		&'s self,
+		elems: &'s E,
+	) -> impl Iterator<Item = Members<'s, E>> + 's
+	where
+		E: ?Sized,
+	{
+		self.roots().map(move |root| self.members(elems, root))
+	}
+
+	/// Groups `elems` by the subset of their index, like `unit::group(..)`.
+	pub(crate) fn group<E, I>(&self, elems: I) -> Vec<Vec<E>>
+	where I: IntoIterator<Item = (E, usize)> {
+		unit::group(&self.set, elems)
+	}
+
+	/// A ticket for the subset rooted at `root`.
+	pub(crate) fn ticket<T>(&self, root: usize) -> SubsetTicket<T> {
+		SubsetTicket {
+			ver: self.ver,
+			id: root,
+			set_id: self.set_id,
+			phantom: PhantomData,
+		}
+	}
+
+	pub(crate) fn is_valid<T>(&self, ticket: &StableTicket<T>) -> bool {
+		let root = self
+			.moved
+			.get(&ticket.generation)
+			.map_or(ticket.id, |&(_, root)| root);
+
+		ticket.set_id == self.set_id
+			&& root < self.set.len()
+			&& self.set[root].parent == root
+			&& self.generations[root] == ticket.generation
+	}
+
+	pub(crate) fn is_current<T>(&self, ticket: &SubsetTicket<T>) -> bool {
+		ticket.set_id == self.set_id && ticket.ver == self.ver
+	}
+
+	/// The root identified by a current ticket.
+	pub(crate) fn ticket_root<T>(
+		&self,
+		ticket: &SubsetTicket<T>,
+	) -> result::Result<usize, StaleTicket> {
+		if self.is_current(ticket) {
+			Ok(ticket.id)
+		} else {
+			Err(StaleTicket)
+		}
+	}
+}
+
+impl<L, C> Subsets<L, C>
+where
+	L: Link,
+	C: Compress,
+{
+	/// Adds the index `len()` in a subset of its own.
+	pub(crate) fn push(&mut self) {
+		let i = self.set.len();
+
+		self.set.push(Unit::new(i));
+		self.next.push(i);
+		self.nodes.push(L::node(0));
+		self.subset_count += 1;
+		self.ver += 1;
+		self.generations.push(self.next_generation);
+		self.next_generation += 1;
+	}
+
+	/// Removes `elem` by moving the last index into its place, like `unit::swap_remove(..)`.
+	/// The other members of the subset of `elem` remain in the same subset.
+	///
+	/// Returns the previous index of the moved index, which is now found at `elem`.
+	pub(crate) fn swap_remove(&mut self, elem: usize) -> usize {
+		let next = self.next[elem];
+		let isolated = self.detach(elem);
+		if !isolated {
+			self.subset_count -= 1;
+		}
+
+		self.unmove(elem);
+		let moved = unit::swap_remove(&mut self.set, elem);
+		unit::swap_remove_next(&mut self.next, elem);
+		self.nodes.swap_remove(elem);
+		self.generations.swap_remove(elem);
+
+		self.ver += 1;
+		self.removed_ver = self.ver;
+
+		if moved != elem && self.set[elem].parent == elem {
+			self.forward(moved, elem);
+		}
+		if isolated {
+			self.renew(if next == moved { elem } else { next });
+		}
+
+		moved
+	}
+
+	pub(crate) fn find(&mut self, elem: usize) -> usize { C::find(&mut self.set, elem) }
+
+	/// Combines the subsets containing `a` and `b`, returning `true` if they were different.
+	pub(crate) fn union(&mut self, a: usize, b: usize) -> bool {
+		let linked = unit::union_with::<L, C>(&mut self.set, &mut self.nodes, a, b);
+		if let Some((root, child)) = linked {
+			unit::splice(&mut self.next, root, child);
+			self.subset_count -= 1;
+			self.ver += 1;
+			self.unmove(child);
+			self.stamp(root);
+		}
+		linked.is_some()
+	}
+
+	/// Detaches `elem` from its subset, returning `true` if a new subset was created.
+	pub(crate) fn isolate(&mut self, elem: usize) -> bool {
+		let next = self.next[elem];
+		let isolated = self.detach(elem);
+		if isolated {
+			self.subset_count += 1;
+			self.ver += 1;
+			self.renew(elem);
+			self.renew(next);
+		}
+		isolated
+	}
+
+	/// Moves the members of the subset containing `elem` for which `predicate` returns `true` into a new subset,
+	/// returning `true` if a new subset was created.
+	pub(crate) fn split<P>(&mut self, elem: usize, predicate: P) -> bool
+	where P: FnMut(&usize) -> bool {
+		let (moved, kept): (Vec<_>, Vec<_>) = unit::members(&self.next, elem).partition(predicate);
+
+		if moved.is_empty() || kept.is_empty() {
+			return false;
+		}
+
+		self.regroup(&moved);
+		self.regroup(&kept);
+
+		self.subset_count += 1;
+		self.ver += 1;
+		self.renew(moved[0]);
+		self.renew(kept[0]);
+
+		true
+	}
+
+	pub(crate) fn find_stable<T>(&mut self, elem: usize) -> StableTicket<T> {
+		let root = self.find(elem);
+		let generation = self.generations[root];
+
+		StableTicket {
+			id: self
+				.moved
+				.get(&generation)
+				.map_or(root, |&(origin, _)| origin),
+			generation,
+			set_id: self.set_id,
+			phantom: PhantomData,
+		}
+	}
+
+	pub(crate) fn refresh<T>(
+		&mut self,
+		ticket: SubsetTicket<T>,
+	) -> result::Result<SubsetTicket<T>, StaleTicket> {
+		if ticket.set_id != self.set_id || ticket.ver < self.removed_ver {
+			return Err(StaleTicket);
+		}
+
+		let root = self.find(ticket.id);
+		Ok(self.ticket(root))
+	}
+
+	/// Gives the subset containing `elem` a new generation, so its `StableTicket`s are no longer valid.
+	fn renew(&mut self, elem: usize) {
+		let root = self.find(elem);
+		self.stamp(root);
+	}
+
+	/// Gives the subset rooted at `root` a new generation.
+	fn stamp(&mut self, root: usize) {
+		self.unmove(root);
+		self.generations[root] = self.next_generation;
+		self.next_generation += 1;
+	}
+
+	/// Records that the root at index `from` was moved to index `to`,
+	/// so the `StableTicket`s of its subset still find it.
+	fn forward(&mut self, from: usize, to: usize) {
+		let generation = self.generations[to];
+		let origin = self
+			.moved
+			.get(&generation)
+			.map_or(from, |&(origin, _)| origin);
+		self.moved.insert(generation, (origin, to));
+	}
+
+	/// Forgets where `elem` was moved to, once its generation can no longer be valid.
+	fn unmove(&mut self, elem: usize) {
+		if !self.moved.is_empty() {
+			self.moved.remove(&self.generations[elem]);
+		}
+	}
+
+	/// Detaches `elem` from its subset like `unit::isolate(..)`,
+	/// also updating the member lists and the nodes of the roots it creates.
+	fn detach(&mut self, elem: usize) -> bool {
+		let members = unit::members(&self.next, elem).collect::<Vec<_>>();
+		let isolated = unit::isolate(&mut self.set, &members);
+		if isolated {
+			let rest = &members[1..];
+			unit::link(&mut self.next, rest);
+			self.nodes[rest[0]] = L::node(height(rest.len()));
+			self.next[elem] = elem;
+			self.nodes[elem] = L::node(0);
+		}
+		isolated
+	}
+
+	/// Makes `members` a subset of their own like `unit::regroup(..)`,
+	/// also updating the member lists and the node of its root.
+	fn regroup(&mut self, members: &[usize]) {
+		unit::regroup(&mut self.set, members);
+		unit::link(&mut self.next, members);
+		self.nodes[members[0]] = L::node(height(members.len()));
+	}
+}
+
+impl<L, C> Default for Subsets<L, C>
+where L: Link
+{
+	fn default() -> Self { Subsets::new(0) }
+}
+
+/// The height of a tree of `size` elements that all point to the root.
+fn height(size: usize) -> usize {
+	if size > 1 {
+		1
+	} else {
+		0
+	}
+}
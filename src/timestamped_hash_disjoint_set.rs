@@ -0,0 +1,279 @@
+use std::{
+	borrow::Borrow,
+	collections::{hash_map::Entry, HashMap},
+	hash,
+	iter,
+	result,
+};
+
+pub use crate::error::HashDisjointSetError;
+use crate::{
+	strategy::{ByRank, NoCompression},
+	unit::{self, Unit},
+};
+
+type Result<T> = result::Result<T, HashDisjointSetError>;
+
+/// A disjoint set that owns its elements and remembers when each union happened,
+/// so it can answer whether two elements were in the same subset at an earlier time.
+///
+/// The time is the version of the set, which starts at `0`
+/// and is increased by every union that combines two subsets.
+/// As with `SubsetTicket`s, a union of elements already in the same subset is not a modification,
+/// and inserting an element does not change the time.
+///
+/// The union operation is done by rank and paths are never compressed,
+/// so the parent pointers are exactly the links made by the unions, each labeled with its time.
+/// Two elements were in the same subset at time `t` if their roots agree
+/// when only following links made at or before `t`.
+/// Every query walks `O(log n)` links and no snapshots are stored.
+pub struct TimestampedHashDisjointSet<T>
+where T: hash::Hash + Eq {
+	ver: usize,
+	map: HashMap<T, usize>,
+	set: Vec<Unit>,
+	ranks: Vec<usize>,
+	times: Vec<usize>,
+	subset_count: usize,
+}
+
+impl<T> Default for TimestampedHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	fn default() -> Self {
+		TimestampedHashDisjointSet {
+			ver: 0,
+			map: HashMap::new(),
+			set: Vec::new(),
+			ranks: Vec::new(),
+			times: Vec::new(),
+			subset_count: 0,
+		}
+	}
+}
+
+impl<T> iter::FromIterator<T> for TimestampedHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = T> {
+		let mut set = TimestampedHashDisjointSet::default();
+		iter.into_iter().for_each(|elem| {
+			let _ = set.insert(elem);
+		});
+		set
+	}
+}
+
+impl<T> TimestampedHashDisjointSet<T>
+where T: hash::Hash + Eq
+{
+	/// Adds an element to the `TimestampedHashDisjointSet`.
+	/// The added element is considered part of a new disjoint subset
+	/// containing only that element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::timestamped_hash_disjoint_set::TimestampedHashDisjointSet;
+	/// #
+	/// let mut set = TimestampedHashDisjointSet::default();
+	/// set.insert("a").unwrap();
+	///
+	/// assert_eq!(1, set.subset_count());
+	/// assert_eq!(0, set.version());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is already in the set.
+	pub fn insert(&mut self, elem: T) -> Result<()> {
+		let set = &mut self.set;
+
+		if let Entry::Vacant(entry) = self.map.entry(elem) {
+			entry.insert(set.len());
+			Ok(())
+		} else {
+			Err(HashDisjointSetError::DuplicateElement)
+		}?;
+
+		set.push(Unit::new(set.len()));
+		self.ranks.push(0);
+		self.times.push(0);
+		self.subset_count += 1;
+
+		Ok(())
+	}
+
+	/// Combine the subsets containing each element.
+	/// If the two elements are already part of the same set, no change occurs.
+	///
+	/// If the subsets were combined, the version of the set is increased,
+	/// and the new version is the time of this union.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::timestamped_hash_disjoint_set::TimestampedHashDisjointSet;
+	/// #
+	/// let mut set: TimestampedHashDisjointSet<_> = vec!["a", "b", "c"].into_iter().collect();
+	/// set.union("a", "b").unwrap();
+	/// set.union("b", "a").unwrap();
+	///
+	/// assert_eq!(1, set.version());
+	/// assert_eq!(2, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn union<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<()>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		let linked =
+			unit::union_with::<ByRank, NoCompression>(&mut self.set, &mut self.ranks, a_i, b_i);
+		if let Some((_, child)) = linked {
+			self.subset_count -= 1;
+			self.ver += 1;
+			self.times[child] = self.ver;
+		}
+
+		Ok(())
+	}
+
+	/// Get the current time, which is the number of unions that have combined two subsets.
+	pub fn version(&self) -> usize { self.ver }
+
+	/// Determine if two elements are in the same subset.
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn same_subset<Q>(&self, elem_a: &Q, elem_b: &Q) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		self.same_subset_at(elem_a, elem_b, self.ver)
+	}
+
+	/// Determine if two elements were in the same subset at time `t`,
+	/// meaning after the union that increased the version to `t`.
+	///
+	/// Times after the current version give the same answer as `same_subset(..)`.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::timestamped_hash_disjoint_set::TimestampedHashDisjointSet;
+	/// #
+	/// let mut set: TimestampedHashDisjointSet<_> = vec!["a", "b", "c"].into_iter().collect();
+	/// set.union("a", "b").unwrap();
+	/// set.union("b", "c").unwrap();
+	///
+	/// assert!(!set.same_subset_at("a", "c", 1).unwrap());
+	/// assert!(set.same_subset_at("a", "c", 2).unwrap());
+	/// assert!(set.same_subset_at("a", "b", 1).unwrap());
+	/// assert!(!set.same_subset_at("a", "b", 0).unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn same_subset_at<Q>(&self, elem_a: &Q, elem_b: &Q, t: usize) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		Ok(self.root_at(a_i, t) == self.root_at(b_i, t))
+	}
+
+	/// Get the earliest time at which the two elements were in the same subset,
+	/// or `None` if they are not in the same subset now.
+	///
+	/// An element is in the same subset as itself at time `0`.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::timestamped_hash_disjoint_set::TimestampedHashDisjointSet;
+	/// #
+	/// let mut set: TimestampedHashDisjointSet<_> = vec!["a", "b", "c", "d"].into_iter().collect();
+	/// set.union("a", "b").unwrap();
+	/// set.union("c", "d").unwrap();
+	/// set.union("b", "d").unwrap();
+	///
+	/// assert_eq!(Ok(Some(1)), set.first_connected_time("b", "a"));
+	/// assert_eq!(Ok(Some(3)), set.first_connected_time("a", "c"));
+	/// assert_eq!(Ok(Some(0)), set.first_connected_time("c", "c"));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn first_connected_time<Q>(&self, elem_a: &Q, elem_b: &Q) -> Result<Option<usize>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		if unit::root(&self.set, a_i) != unit::root(&self.set, b_i) {
+			return Ok(None);
+		}
+
+		// the elements were connected once every link on the path between them was made
+		let mut path_a = vec![a_i];
+		let mut elem = a_i;
+		while self.set[elem].parent != elem {
+			elem = self.set[elem].parent;
+			path_a.push(elem);
+		}
+
+		let mut elem = b_i;
+		let mut latest_b = 0;
+		loop {
+			if let Some(common) = path_a.iter().position(|&ancestor| ancestor == elem) {
+				let latest_a = path_a[..common]
+					.iter()
+					.map(|&i| self.times[i])
+					.max()
+					.unwrap_or(0);
+				return Ok(Some(latest_a.max(latest_b)));
+			}
+
+			latest_b = latest_b.max(self.times[elem]);
+			elem = self.set[elem].parent;
+		}
+	}
+
+	/// Get the number of disjoint subsets in the set.
+	pub fn subset_count(&self) -> usize { self.subset_count }
+
+	/// Get the number of elements in the subset containing the provided element.
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_size<Q>(&self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		Ok(self.set[unit::root(&self.set, i)].size)
+	}
+
+	/// Finds the root of `elem` at time `t` by only following links made at or before `t`.
+	fn root_at(&self, elem: usize, t: usize) -> usize {
+		let mut elem = elem;
+		while self.set[elem].parent != elem && self.times[elem] <= t {
+			elem = self.set[elem].parent;
+		}
+		elem
+	}
+
+	fn index<Q>(&self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		Ok(*self
+			.map
+			.get(elem)
+			.ok_or(HashDisjointSetError::ElementNotDefined)?)
+	}
+}
@@ -0,0 +1,122 @@
+use crate::{
+	disjoint_set::DisjointSet,
+	timestamped_hash_disjoint_set::{HashDisjointSetError, TimestampedHashDisjointSet},
+};
+
+fn letters() -> TimestampedHashDisjointSet<char> { "abcdefgh".chars().collect() }
+
+#[test]
+fn create_default() {
+	let set = TimestampedHashDisjointSet::<u8>::default();
+	assert_eq!(0, set.subset_count());
+	assert_eq!(0, set.version());
+}
+
+#[test]
+fn insert_duplicate_err() {
+	let mut set = letters();
+	assert_eq!(Err(HashDisjointSetError::DuplicateElement), set.insert('a'));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn undefined_err() {
+	let mut set = letters();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.union(&'a', &'z')
+	);
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.same_subset_at(&'z', &'a', 0)
+	);
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.first_connected_time(&'a', &'z')
+	);
+}
+
+#[test]
+fn redundant_union_keeps_version() {
+	let mut set = letters();
+	set.union(&'a', &'b').unwrap();
+	set.union(&'b', &'a').unwrap();
+	set.insert('z').unwrap();
+
+	assert_eq!(1, set.version());
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn same_subset_at_each_time() {
+	let mut set = letters();
+	set.union(&'a', &'b').unwrap();
+	set.union(&'c', &'d').unwrap();
+	set.union(&'a', &'d').unwrap();
+	set.union(&'e', &'a').unwrap();
+
+	assert!(!set.same_subset_at(&'a', &'b', 0).unwrap());
+	assert!(set.same_subset_at(&'a', &'b', 1).unwrap());
+	assert!(!set.same_subset_at(&'b', &'c', 2).unwrap());
+	assert!(set.same_subset_at(&'b', &'c', 3).unwrap());
+	assert!(!set.same_subset_at(&'e', &'c', 3).unwrap());
+	assert!(set.same_subset_at(&'e', &'c', 4).unwrap());
+	assert!(set.same_subset_at(&'e', &'c', 100).unwrap());
+	assert!(!set.same_subset_at(&'f', &'a', 100).unwrap());
+	assert!(set.same_subset(&'e', &'b').unwrap());
+	assert_eq!(5, set.subset_size(&'c').unwrap());
+}
+
+#[test]
+fn first_connected_time_of_pairs() {
+	let mut set = letters();
+	set.union(&'a', &'b').unwrap();
+	set.union(&'c', &'d').unwrap();
+	set.union(&'a', &'d').unwrap();
+	set.union(&'e', &'a').unwrap();
+
+	assert_eq!(Ok(Some(0)), set.first_connected_time(&'a', &'a'));
+	assert_eq!(Ok(Some(1)), set.first_connected_time(&'b', &'a'));
+	assert_eq!(Ok(Some(2)), set.first_connected_time(&'d', &'c'));
+	assert_eq!(Ok(Some(3)), set.first_connected_time(&'b', &'c'));
+	assert_eq!(Ok(Some(4)), set.first_connected_time(&'d', &'e'));
+	assert_eq!(Ok(None), set.first_connected_time(&'a', &'h'));
+}
+
+#[test]
+fn matches_snapshots() {
+	let n = 60;
+	let mut set: TimestampedHashDisjointSet<usize> = (0..n).collect();
+	let mut snapshot = DisjointSet::new(n);
+	let mut snapshots = vec![snapshot.all_subsets()];
+
+	for i in 0..n {
+		let (a, b) = ((i * 7) % n, (i * 11 + 3) % n);
+		set.union(&a, &b).unwrap();
+		if snapshot.union(a, b) {
+			snapshots.push(snapshot.all_subsets());
+		}
+	}
+
+	assert_eq!(snapshots.len() - 1, set.version());
+
+	for (t, subsets) in snapshots.iter().enumerate() {
+		for subset in subsets {
+			for &a in subset {
+				assert!(set.same_subset_at(&a, &subset[0], t).unwrap());
+			}
+		}
+		for pair in subsets.windows(2) {
+			assert!(!set.same_subset_at(&pair[0][0], &pair[1][0], t).unwrap());
+		}
+	}
+
+	for a in 0..n {
+		for b in 0..n {
+			let expected = snapshots
+				.iter()
+				.position(|subsets| subsets.iter().any(|s| s.contains(&a) && s.contains(&b)));
+			assert_eq!(Ok(expected), set.first_connected_time(&a, &b));
+		}
+	}
+}
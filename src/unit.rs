@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::strategy::{BySize, Compress, Link, PathSplitting};
+
+/// A single node of the parent pointer forest shared by the disjoint set implementations.
+///
+/// `size` is only meaningful for roots.
+pub(crate) struct Unit {
+	pub(crate) size: usize,
+	pub(crate) parent: usize,
+}
+
+impl Unit {
+	pub(crate) fn new(index: usize) -> Self {
+		Unit {
+			size: 1,
+			parent: index,
+		}
+	}
+}
+
+/// Finds the root of `elem`, splitting the path along the way.
+pub(crate) fn find(set: &mut [Unit], elem: usize) -> usize {
+	let mut elem = elem;
+	while set[elem].parent != elem {
+		let grandparent = set[elem].parent;
+		set[elem].parent = set[grandparent].parent;
+		elem = grandparent;
+	}
+	elem
+}
+
+/// Finds the root of `elem` without modifying the set.
+pub(crate) fn root(set: &[Unit], elem: usize) -> usize {
+	let mut elem = elem;
+	while set[elem].parent != elem {
+		elem = set[elem].parent;
+	}
+	elem
+}
+
+/// Links the subsets containing `a` and `b` by size.
+///
+/// If the subsets were different, returns the root of the combined subset
+/// followed by the root that was linked below it.
+pub(crate) fn union(set: &mut [Unit], a: usize, b: usize) -> Option<(usize, usize)> {
+	// linking by size keeps no nodes
+	union_with::<BySize, PathSplitting>(set, &mut [], a, b)
+}
+
+/// Links the subsets containing `a` and `b` using the provided strategies,
+/// where `nodes` holds the linking strategy's node for each element.
+///
+/// If the subsets were different, returns the root of the combined subset
+/// followed by the root that was linked below it.
+pub(crate) fn union_with<L, C>(
+	set: &mut [Unit],
+	nodes: &mut [L::Node],
+	a: usize,
+	b: usize,
+) -> Option<(usize, usize)>
+where
+	L: Link,
+	C: Compress,
+{
+	let root_a = C::find(set, a);
+	let root_b = C::find(set, b);
+
+	if root_a == root_b {
+		return None;
+	}
+
+	let (root, child) = L::order(set, nodes, root_a, root_b);
+	set[child].parent = root;
+	set[root].size += set[child].size;
+
+	Some((root, child))
+}
+
+/// Detaches the first of `members`, which are all the members of a subset, leaving it as a singleton
+/// while the remaining members of the subset stay connected below the second member.
+///
+/// Returns `true` if the subset had other members, meaning a new subset has been created.
+/// This runs in linear time in the size of the subset.
+pub(crate) fn isolate(set: &mut [Unit], members: &[usize]) -> bool {
+	if members.len() < 2 {
+		return false;
+	}
+
+	regroup(set, &members[1..]);
+	set[members[0]] = Unit::new(members[0]);
+
+	true
+}
+
+/// Makes `members` a subset of their own, with the first member as its root.
+///
+/// Every member of the subset that `members` were taken from has to be regrouped,
+/// otherwise the remaining members could still point into `members`.
+pub(crate) fn regroup(set: &mut [Unit], members: &[usize]) {
+	let root = members[0];
+
+	members.iter().for_each(|&i| set[i].parent = root);
+	set[root].size = members.len();
+}
+
+// Sets that enumerate the members of a subset keep a `next` list alongside the forest,
+// where the members of each subset form a circular list,
+// so a subset can be enumerated in time proportional to its size.
+
+/// Makes `members` a circular list of their own in `next`.
+pub(crate) fn link(next: &mut [usize], members: &[usize]) {
+	members
+		.iter()
+		.zip(members.iter().cycle().skip(1))
+		.for_each(|(&i, &following)| next[i] = following);
+}
+
+/// Joins the member lists of two different subsets by swapping the successors of a member of each.
+///
+/// Swapping the same successors again separates the lists.
+pub(crate) fn splice(next: &mut [usize], a: usize, b: usize) { next.swap(a, b); }
+
+/// Iterates over the indices of the members of the subset containing `elem`, starting with `elem`.
+pub(crate) fn members(next: &[usize], elem: usize) -> Members<'_> {
+	Members {
+		next_of: next,
+		start: elem,
+		next: Some(elem),
+	}
+}
+
+/// The iterator returned by `members(..)`.
+#[derive(Clone)]
+pub(crate) struct Members<'s> {
+	next_of: &'s [usize],
+	start: usize,
+	next: Option<usize>,
+}
+
+impl Iterator for Members<'_> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		let current = self.next?;
+		let following = self.next_of[current];
+		self.next = if following == self.start {
+			None
+		} else {
+			Some(following)
+		};
+		Some(current)
+	}
+}
+
+/// Removes the singleton `elem` from `set` by moving the last unit into its place.
+///
+/// Returns the previous index of the moved unit, which is now found at `elem`.
+pub(crate) fn swap_remove(set: &mut Vec<Unit>, elem: usize) -> usize {
+	let last = set.len() - 1;
+	set.swap_remove(elem);
+
+	if elem != last {
+		set.iter_mut()
+			.filter(|unit| unit.parent == last)
+			.for_each(|unit| unit.parent = elem);
+	}
+
+	last
+}
+
+/// Removes the singleton `elem` from the member lists in `next`, in the same way as `swap_remove(..)`.
+pub(crate) fn swap_remove_next(next: &mut Vec<usize>, elem: usize) {
+	let last = next.len() - 1;
+	next.swap_remove(elem);
+
+	if elem != last {
+		next.iter_mut()
+			.filter(|following| **following == last)
+			.for_each(|following| *following = elem);
+	}
+}
+
+/// Groups `elems` by the root of their index without modifying the set.
+///
+/// Subsets appear in the order their first element is provided.
+pub(crate) fn group<E, I>(set: &[Unit], elems: I) -> Vec<Vec<E>>
+where I: IntoIterator<Item = (E, usize)> {
+	let mut subset_map = HashMap::new();
+	let mut subsets: Vec<Vec<E>> = Vec::new();
+
+	elems.into_iter().for_each(|(elem, i)| {
+		let root = root(set, i);
+		let entry = *subset_map.entry(root).or_insert_with(|| {
+			subsets.push(Vec::with_capacity(set[root].size));
+			subsets.len() - 1
+		});
+		subsets[entry].push(elem);
+	});
+
+	subsets
+}
+
+/// The order in which roots are linked: the root with the lower priority is placed below the other.
+///
+/// Mixing the bits of the index acts as a fixed random permutation of the elements.
+pub(crate) fn priority(elem: usize) -> (u64, usize) {
+	let mut x = (elem as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+	x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+	x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+	(x ^ (x >> 31), elem)
+}
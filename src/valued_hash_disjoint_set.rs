@@ -0,0 +1,326 @@
+use std::{
+	borrow::Borrow,
+	collections::{hash_map::Entry, HashMap},
+	hash,
+	iter,
+	marker::PhantomData,
+	ops::AddAssign,
+	result,
+};
+
+pub use crate::error::HashDisjointSetError;
+use crate::{
+	next_set_id,
+	unit::{self, Unit},
+	SubsetTicket,
+};
+
+type Result<T> = result::Result<T, HashDisjointSetError>;
+
+const ROOT_VALUE: &str = "the root of every subset holds its value";
+
+/// A value that is kept for each subset of a `ValuedHashDisjointSet`.
+///
+/// When two subsets are combined, the value of one subset is merged into the value of the other.
+/// Which value is kept depends on the sizes of the subsets,
+/// so merging should be associative and commutative, like a sum, a minimum or a count.
+///
+/// `Sum`, `Min` and `Max` cover the common cases, and pairs of values are merged element-wise.
+pub trait Merge {
+	/// Combines `other`, the value of a subset that is being absorbed, into `self`.
+	fn merge(&mut self, other: Self);
+}
+
+/// Adds the values of merged subsets.
+///
+/// Counting the members of a subset that have some property
+/// is done by starting each element with `Sum(1)` or `Sum(0)`.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Sum<N>(pub N);
+
+/// Keeps the smallest value of merged subsets.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Min<N>(pub N);
+
+/// Keeps the largest value of merged subsets.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Max<N>(pub N);
+
+impl<N> Merge for Sum<N>
+where N: AddAssign
+{
+	fn merge(&mut self, other: Self) { self.0 += other.0; }
+}
+
+impl<N> Merge for Min<N>
+where N: Ord
+{
+	fn merge(&mut self, other: Self) {
+		if other.0 < self.0 {
+			self.0 = other.0;
+		}
+	}
+}
+
+impl<N> Merge for Max<N>
+where N: Ord
+{
+	fn merge(&mut self, other: Self) {
+		if other.0 > self.0 {
+			self.0 = other.0;
+		}
+	}
+}
+
+impl Merge for () {
+	fn merge(&mut self, _: Self) {}
+}
+
+impl<A, B> Merge for (A, B)
+where
+	A: Merge,
+	B: Merge,
+{
+	fn merge(&mut self, other: Self) {
+		self.0.merge(other.0);
+		self.1.merge(other.1);
+	}
+}
+
+/// A disjoint set that owns its elements and keeps an aggregate value for each subset.
+///
+/// Each element is inserted with an initial value for its singleton subset.
+/// The values are combined with `Merge::merge(..)` whenever a union combines two subsets,
+/// so per-subset sums, minima or counts are available without iterating over the members.
+///
+/// Path splitting is used. The union operation is done by size.
+pub struct ValuedHashDisjointSet<T, V>
+where T: hash::Hash + Eq {
+	ver: usize,
+	map: HashMap<T, usize>,
+	set: Vec<Unit>,
+	values: Vec<Option<V>>,
+	subset_count: usize,
+	set_id: usize,
+}
+
+impl<T, V> Default for ValuedHashDisjointSet<T, V>
+where T: hash::Hash + Eq
+{
+	fn default() -> Self {
+		ValuedHashDisjointSet {
+			ver: 0,
+			map: HashMap::new(),
+			set: Vec::new(),
+			values: Vec::new(),
+			subset_count: 0,
+			set_id: next_set_id(),
+		}
+	}
+}
+
+impl<T, V> iter::FromIterator<(T, V)> for ValuedHashDisjointSet<T, V>
+where
+	T: hash::Hash + Eq,
+	V: Merge,
+{
+	/// Creates a set from elements and their initial values.
+	/// If an element is repeated, only its first value is used.
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = (T, V)> {
+		let mut set = ValuedHashDisjointSet::default();
+		iter.into_iter().for_each(|(elem, value)| {
+			let _ = set.insert(elem, value);
+		});
+		set.ver = 0;
+		set
+	}
+}
+
+impl<T, V> ValuedHashDisjointSet<T, V>
+where
+	T: hash::Hash + Eq,
+	V: Merge,
+{
+	/// Adds an element to the `ValuedHashDisjointSet`.
+	/// The added element is considered part of a new disjoint subset
+	/// containing only that element, and `value` becomes the value of that subset.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::valued_hash_disjoint_set::{Sum, ValuedHashDisjointSet};
+	/// #
+	/// let mut set = ValuedHashDisjointSet::default();
+	/// set.insert("a", Sum(3)).unwrap();
+	///
+	/// assert_eq!(3, set.subset_value("a").unwrap().0);
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is already in the set.
+	pub fn insert(&mut self, elem: T, value: V) -> Result<()> {
+		let set = &mut self.set;
+
+		if let Entry::Vacant(entry) = self.map.entry(elem) {
+			entry.insert(set.len());
+			Ok(())
+		} else {
+			Err(HashDisjointSetError::DuplicateElement)
+		}?;
+
+		set.push(Unit::new(set.len()));
+		self.values.push(Some(value));
+		self.subset_count += 1;
+		self.ver += 1;
+
+		Ok(())
+	}
+
+	/// Combine the subsets containing each element, merging their values.
+	/// If the two elements are already part of the same set, no change occurs.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::valued_hash_disjoint_set::{Sum, ValuedHashDisjointSet};
+	/// #
+	/// let values = vec![("a", Sum(3)), ("b", Sum(4)), ("c", Sum(5))];
+	/// let mut set: ValuedHashDisjointSet<_, _> = values.into_iter().collect();
+	/// set.union("a", "b").unwrap();
+	///
+	/// assert_eq!(7, set.subset_value("b").unwrap().0);
+	/// assert_eq!(5, set.subset_value("c").unwrap().0);
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn union<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<()>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		if let Some((root, child)) = unit::union(&mut self.set, a_i, b_i) {
+			let child_value = self.values[child].take().expect(ROOT_VALUE);
+			self.values[root]
+				.as_mut()
+				.expect(ROOT_VALUE)
+				.merge(child_value);
+
+			self.subset_count -= 1;
+			self.ver += 1;
+		}
+
+		Ok(())
+	}
+
+	/// Get the value of the subset containing the provided element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::valued_hash_disjoint_set::{Min, ValuedHashDisjointSet};
+	/// #
+	/// let values = vec![("a", Min(3)), ("b", Min(4)), ("c", Min(1))];
+	/// let mut set: ValuedHashDisjointSet<_, _> = values.into_iter().collect();
+	/// set.union("b", "c").unwrap();
+	///
+	/// assert_eq!(Min(1), *set.subset_value("b").unwrap());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_value<Q>(&mut self, elem: &Q) -> Result<&V>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let root = self.root(elem)?;
+		Ok(self.values[root].as_ref().expect(ROOT_VALUE))
+	}
+
+	/// Get a mutable reference to the value of the subset containing the provided element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::valued_hash_disjoint_set::{Sum, ValuedHashDisjointSet};
+	/// #
+	/// let values = vec![("a", Sum(3)), ("b", Sum(4))];
+	/// let mut set: ValuedHashDisjointSet<_, _> = values.into_iter().collect();
+	/// set.union("a", "b").unwrap();
+	/// set.subset_value_mut("a").unwrap().0 += 10;
+	///
+	/// assert_eq!(17, set.subset_value("b").unwrap().0);
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_value_mut<Q>(&mut self, elem: &Q) -> Result<&mut V>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let root = self.root(elem)?;
+		Ok(self.values[root].as_mut().expect(ROOT_VALUE))
+	}
+
+	/// Identify the subset of an element.
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn find<Q>(&mut self, elem: &Q) -> Result<SubsetTicket<T>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		Ok(SubsetTicket {
+			ver: self.ver,
+			id: self.root(elem)?,
+			set_id: self.set_id,
+			phantom: PhantomData,
+		})
+	}
+
+	/// Determine if two elements are in the same subset.
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn same_subset<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let root_a = self.root(elem_a)?;
+		let root_b = self.root(elem_b)?;
+
+		Ok(root_a == root_b)
+	}
+
+	/// Get the number of disjoint subsets in the set.
+	pub fn subset_count(&self) -> usize { self.subset_count }
+
+	/// Get the number of elements in the subset containing the provided element.
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_size<Q>(&mut self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let root = self.root(elem)?;
+		Ok(self.set[root].size)
+	}
+
+	fn root<Q>(&mut self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		Ok(unit::find(&mut self.set, i))
+	}
+
+	fn index<Q>(&self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		Ok(*self
+			.map
+			.get(elem)
+			.ok_or(HashDisjointSetError::ElementNotDefined)?)
+	}
+}
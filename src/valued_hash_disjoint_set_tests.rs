@@ -0,0 +1,159 @@
+use crate::valued_hash_disjoint_set::{
+	HashDisjointSetError,
+	Max,
+	Merge,
+	Min,
+	Sum,
+	ValuedHashDisjointSet,
+};
+
+fn weights() -> ValuedHashDisjointSet<&'static str, Sum<u32>> {
+	vec![
+		("a", Sum(1)),
+		("b", Sum(2)),
+		("c", Sum(4)),
+		("d", Sum(8)),
+		("e", Sum(16)),
+	]
+	.into_iter()
+	.collect()
+}
+
+#[test]
+fn create_default() { ValuedHashDisjointSet::<u8, Sum<u8>>::default(); }
+
+#[test]
+fn from_iter_first_value_kept() {
+	let mut set: ValuedHashDisjointSet<_, _> =
+		vec![("a", Sum(1)), ("a", Sum(2))].into_iter().collect();
+	assert_eq!(1, set.subset_count());
+	assert_eq!(Ok(&Sum(1)), set.subset_value("a"));
+}
+
+#[test]
+fn insert_err() {
+	let mut set = weights();
+	assert_eq!(
+		Err(HashDisjointSetError::DuplicateElement),
+		set.insert("a", Sum(100))
+	);
+	assert_eq!(Ok(&Sum(1)), set.subset_value("a"));
+}
+
+#[test]
+fn singleton_value() {
+	let mut set = weights();
+	assert_eq!(Ok(&Sum(4)), set.subset_value("c"));
+}
+
+#[test]
+fn union_sum_value() {
+	let mut set = weights();
+	set.union("a", "b").unwrap();
+	set.union("c", "d").unwrap();
+	set.union("b", "d").unwrap();
+
+	assert_eq!(Ok(&Sum(15)), set.subset_value("a"));
+	assert_eq!(Ok(&Sum(15)), set.subset_value("d"));
+	assert_eq!(Ok(&Sum(16)), set.subset_value("e"));
+	assert_eq!(2, set.subset_count());
+	assert_eq!(4, set.subset_size("c").unwrap());
+}
+
+#[test]
+fn union_same_subset_value() {
+	let mut set = weights();
+	set.union("a", "b").unwrap();
+	set.union("b", "a").unwrap();
+	set.union("a", "a").unwrap();
+
+	assert_eq!(Ok(&Sum(3)), set.subset_value("b"));
+}
+
+#[test]
+fn union_err_value() {
+	let mut set = weights();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.union("a", "z")
+	);
+	assert_eq!(Ok(&Sum(1)), set.subset_value("a"));
+}
+
+#[test]
+fn subset_value_err() {
+	let mut set = weights();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_value("z")
+	);
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.subset_value_mut("z").map(|value| value.0)
+	);
+}
+
+#[test]
+fn subset_value_mut_shared() {
+	let mut set = weights();
+	set.union("a", "b").unwrap();
+	set.subset_value_mut("b").unwrap().0 = 0;
+	set.union("c", "a").unwrap();
+
+	assert_eq!(Ok(&Sum(4)), set.subset_value("a"));
+}
+
+#[test]
+fn min_max_pair_value() {
+	let mut set: ValuedHashDisjointSet<_, _> =
+		(1..=6).map(|i| (i, (Min(i * 10 % 7), Max(i)))).collect();
+	set.union(&1, &2).unwrap();
+	set.union(&3, &4).unwrap();
+	set.union(&2, &4).unwrap();
+
+	assert_eq!(Ok(&(Min(2), Max(4))), set.subset_value(&3));
+	assert_eq!(Ok(&(Min(1), Max(5))), set.subset_value(&5));
+}
+
+#[test]
+fn count_flagged() {
+	let mut set: ValuedHashDisjointSet<_, _> = (0..10)
+		.map(|i| (i, Sum(if i % 3 == 0 { 1 } else { 0 })))
+		.collect();
+	(1..5).for_each(|i| set.union(&0, &i).unwrap());
+
+	assert_eq!(Ok(&Sum(2)), set.subset_value(&4));
+	assert_eq!(Ok(&Sum(1)), set.subset_value(&9));
+}
+
+#[test]
+fn custom_merge() {
+	#[derive(Debug, PartialEq)]
+	struct Members(Vec<u8>);
+
+	impl Merge for Members {
+		fn merge(&mut self, other: Self) { self.0.extend(other.0); }
+	}
+
+	let mut set: ValuedHashDisjointSet<_, _> = (0..4).map(|i| (i, Members(vec![i]))).collect();
+	set.union(&0, &3).unwrap();
+	set.union(&2, &3).unwrap();
+
+	let mut members = set.subset_value(&0).unwrap().0.clone();
+	members.sort_unstable();
+	assert_eq!(vec![0, 2, 3], members);
+}
+
+#[test]
+fn find_same_subset() {
+	let mut set = weights();
+	set.union("a", "e").unwrap();
+
+	assert_eq!(set.find("a").unwrap(), set.find("e").unwrap());
+	assert!(set.same_subset("e", "a").unwrap());
+	assert!(!set.same_subset("b", "a").unwrap());
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.same_subset("z", "a")
+	);
+}
@@ -0,0 +1,293 @@
+use std::{
+	borrow::Borrow,
+	collections::{hash_map::Entry, HashMap},
+	hash,
+	iter,
+	mem,
+	result,
+};
+
+pub use crate::error::{HashDisjointSetError, RelationError};
+use crate::unit::Unit;
+
+type Result<T> = result::Result<T, HashDisjointSetError>;
+
+/// A weight that can describe the difference between two elements of a `WeightedHashDisjointSet`.
+///
+/// The weights must form a commutative group:
+/// `combine(..)` is associative and commutative, `identity()` does not change a weight it is combined with,
+/// and combining a weight with its `inverse()` gives the identity.
+///
+/// The integer types implement this trait with wrapping arithmetic.
+pub trait Group: Clone + PartialEq {
+	/// The weight of an element relative to itself.
+	fn identity() -> Self;
+
+	/// Adds two weights.
+	fn combine(&self, other: &Self) -> Self;
+
+	/// Negates a weight.
+	fn inverse(&self) -> Self;
+}
+
+macro_rules! impl_group {
+	($($t:ty),*) => {
+		$(
+			impl Group for $t {
+				fn identity() -> Self { 0 }
+
+				fn combine(&self, other: &Self) -> Self { self.wrapping_add(*other) }
+
+				fn inverse(&self) -> Self { self.wrapping_neg() }
+			}
+		)*
+	};
+}
+
+impl_group!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A disjoint set that owns its elements and keeps the difference between elements of the same subset.
+///
+/// Each element has a potential relative to the root of its subset,
+/// so relations like `x - y = 5` can be recorded with `union_with_diff(..)`
+/// and queried for any two elements of the same subset with `diff(..)`.
+/// The potentials are updated while paths are split.
+///
+/// Path splitting is used. The union operation is done by size.
+pub struct WeightedHashDisjointSet<T, W>
+where T: hash::Hash + Eq {
+	map: HashMap<T, usize>,
+	set: Vec<Unit>,
+	potentials: Vec<W>,
+	subset_count: usize,
+}
+
+impl<T, W> Default for WeightedHashDisjointSet<T, W>
+where T: hash::Hash + Eq
+{
+	fn default() -> Self {
+		WeightedHashDisjointSet {
+			map: HashMap::new(),
+			set: Vec::new(),
+			potentials: Vec::new(),
+			subset_count: 0,
+		}
+	}
+}
+
+impl<T, W> iter::FromIterator<T> for WeightedHashDisjointSet<T, W>
+where
+	T: hash::Hash + Eq,
+	W: Group,
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = T> {
+		let mut set = WeightedHashDisjointSet::default();
+		iter.into_iter().for_each(|elem| {
+			let _ = set.insert(elem);
+		});
+		set
+	}
+}
+
+impl<T, W> WeightedHashDisjointSet<T, W>
+where
+	T: hash::Hash + Eq,
+	W: Group,
+{
+	/// Adds an element to the `WeightedHashDisjointSet`.
+	/// The added element is considered part of a new disjoint subset
+	/// containing only that element.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::weighted_hash_disjoint_set::WeightedHashDisjointSet;
+	/// #
+	/// let mut set = WeightedHashDisjointSet::<_, i32>::default();
+	/// set.insert("x").unwrap();
+	///
+	/// assert_eq!(1, set.subset_count());
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if the provided element is already in the set.
+	pub fn insert(&mut self, elem: T) -> Result<()> {
+		let set = &mut self.set;
+
+		if let Entry::Vacant(entry) = self.map.entry(elem) {
+			entry.insert(set.len());
+			Ok(())
+		} else {
+			Err(HashDisjointSetError::DuplicateElement)
+		}?;
+
+		set.push(Unit::new(set.len()));
+		self.potentials.push(W::identity());
+		self.subset_count += 1;
+
+		Ok(())
+	}
+
+	/// Records that `elem_a - elem_b = weight`, combining the subsets containing each element.
+	///
+	/// If the elements are already part of the same subset, no change occurs
+	/// as long as the relation agrees with the relations already in the set.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::weighted_hash_disjoint_set::WeightedHashDisjointSet;
+	/// #
+	/// let mut set: WeightedHashDisjointSet<_, i32> = vec!["x", "y", "z"].into_iter().collect();
+	/// set.union_with_diff("x", "y", 5).unwrap();
+	/// set.union_with_diff("y", "z", 3).unwrap();
+	///
+	/// assert_eq!(Ok(Some(8)), set.diff("x", "z"));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set,
+	/// or if the relation contradicts the relations already in the set.
+	/// ```
+	/// # use union_find::weighted_hash_disjoint_set::{RelationError, WeightedHashDisjointSet};
+	/// #
+	/// let mut set: WeightedHashDisjointSet<_, i32> = vec!["x", "y", "z"].into_iter().collect();
+	/// set.union_with_diff("x", "y", 5).unwrap();
+	/// set.union_with_diff("y", "z", 3).unwrap();
+	/// let result = set.union_with_diff("x", "z", 7).unwrap_err();
+	///
+	/// assert_eq!(RelationError::Contradiction, result);
+	/// ```
+	pub fn union_with_diff<Q>(
+		&mut self,
+		elem_a: &Q,
+		elem_b: &Q,
+		weight: W,
+	) -> result::Result<(), RelationError>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized,
+	{
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		let (mut root_a, potential_a) = self.find_internal(a_i);
+		let (mut root_b, potential_b) = self.find_internal(b_i);
+
+		// the potential of `root_b` relative to `root_a`
+		let mut offset = potential_a
+			.combine(&weight.inverse())
+			.combine(&potential_b.inverse());
+
+		if root_a == root_b {
+			return if offset == W::identity() {
+				Ok(())
+			} else {
+				Err(RelationError::Contradiction)
+			};
+		}
+
+		if self.set[root_a].size < self.set[root_b].size {
+			mem::swap(&mut root_a, &mut root_b);
+			offset = offset.inverse();
+		}
+
+		self.set[root_b].parent = root_a;
+		self.set[root_a].size += self.set[root_b].size;
+		self.potentials[root_b] = offset;
+		self.subset_count -= 1;
+
+		Ok(())
+	}
+
+	/// Get `elem_a - elem_b` if the elements are in the same subset, or `None` if they are not.
+	///
+	/// # Examples
+	/// ```
+	/// # use union_find::weighted_hash_disjoint_set::WeightedHashDisjointSet;
+	/// #
+	/// let mut set: WeightedHashDisjointSet<_, i32> = vec!["x", "y", "z"].into_iter().collect();
+	/// set.union_with_diff("x", "y", 5).unwrap();
+	///
+	/// assert_eq!(Ok(Some(-5)), set.diff("y", "x"));
+	/// assert_eq!(Ok(None), set.diff("x", "z"));
+	/// ```
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn diff<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<Option<W>>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		let (root_a, potential_a) = self.find_internal(a_i);
+		let (root_b, potential_b) = self.find_internal(b_i);
+
+		Ok(if root_a == root_b {
+			Some(potential_a.combine(&potential_b.inverse()))
+		} else {
+			None
+		})
+	}
+
+	/// Determine if two elements are in the same subset.
+	///
+	/// # Failures
+	/// An error is returned if at least one of the provided elements are not in the set.
+	pub fn same_subset<Q>(&mut self, elem_a: &Q, elem_b: &Q) -> Result<bool>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let a_i = self.index(elem_a)?;
+		let b_i = self.index(elem_b)?;
+
+		Ok(self.find_internal(a_i).0 == self.find_internal(b_i).0)
+	}
+
+	/// Get the number of disjoint subsets in the set.
+	pub fn subset_count(&self) -> usize { self.subset_count }
+
+	/// Get the number of elements in the subset containing the provided element.
+	///
+	/// # Failures
+	/// An error is returned if the provided element is not in the set.
+	pub fn subset_size<Q>(&mut self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		let i = self.index(elem)?;
+		let (root, _) = self.find_internal(i);
+		Ok(self.set[root].size)
+	}
+
+	/// Finds the root of `elem` and the potential of `elem` relative to it, splitting the path along the way.
+	fn find_internal(&mut self, elem: usize) -> (usize, W) {
+		let mut elem = elem;
+		let mut potential = W::identity();
+
+		while self.set[elem].parent != elem {
+			let parent = self.set[elem].parent;
+			potential = potential.combine(&self.potentials[elem]);
+
+			let grandparent = self.set[parent].parent;
+			if grandparent != parent {
+				self.potentials[elem] = self.potentials[elem].combine(&self.potentials[parent]);
+				self.set[elem].parent = grandparent;
+			}
+			elem = parent;
+		}
+
+		(elem, potential)
+	}
+
+	fn index<Q>(&self, elem: &Q) -> Result<usize>
+	where
+		T: Borrow<Q>,
+		Q: hash::Hash + Eq + ?Sized, {
+		Ok(*self
+			.map
+			.get(elem)
+			.ok_or(HashDisjointSetError::ElementNotDefined)?)
+	}
+}
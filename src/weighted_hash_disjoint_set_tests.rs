@@ -0,0 +1,139 @@
+use crate::weighted_hash_disjoint_set::{
+	Group,
+	HashDisjointSetError,
+	RelationError,
+	WeightedHashDisjointSet,
+};
+
+fn letters() -> WeightedHashDisjointSet<char, i64> { "abcdefgh".chars().collect() }
+
+#[test]
+fn create_default() { WeightedHashDisjointSet::<u8, i32>::default(); }
+
+#[test]
+fn insert_duplicate_err() {
+	let mut set = letters();
+	assert_eq!(Err(HashDisjointSetError::DuplicateElement), set.insert('a'));
+	assert_eq!(8, set.subset_count());
+}
+
+#[test]
+fn diff_self_is_identity() {
+	let mut set = letters();
+	assert_eq!(Ok(Some(0)), set.diff(&'c', &'c'));
+}
+
+#[test]
+fn diff_different_subsets() {
+	let mut set = letters();
+	set.union_with_diff(&'a', &'b', 3).unwrap();
+	assert_eq!(Ok(None), set.diff(&'a', &'c'));
+}
+
+#[test]
+fn diff_undefined_err() {
+	let mut set = letters();
+	assert_eq!(
+		Err(HashDisjointSetError::ElementNotDefined),
+		set.diff(&'a', &'z')
+	);
+	assert_eq!(
+		Err(RelationError::Element(
+			HashDisjointSetError::ElementNotDefined
+		)),
+		set.union_with_diff(&'z', &'a', 1)
+	);
+}
+
+#[test]
+fn diff_through_chain() {
+	let mut set = letters();
+	set.union_with_diff(&'a', &'b', 1).unwrap();
+	set.union_with_diff(&'c', &'b', -4).unwrap();
+	set.union_with_diff(&'d', &'e', 10).unwrap();
+	set.union_with_diff(&'e', &'c', 2).unwrap();
+
+	// a = b + 1, c = b - 4, e = c + 2, d = e + 10
+	assert_eq!(Ok(Some(-7)), set.diff(&'a', &'d'));
+	assert_eq!(Ok(Some(7)), set.diff(&'d', &'a'));
+	assert_eq!(Ok(Some(3)), set.diff(&'a', &'e'));
+	assert_eq!(4, set.subset_count());
+	assert_eq!(Ok(5), set.subset_size(&'b'));
+}
+
+#[test]
+fn consistent_relation_accepted() {
+	let mut set = letters();
+	set.union_with_diff(&'a', &'b', 2).unwrap();
+	set.union_with_diff(&'b', &'c', 3).unwrap();
+
+	assert_eq!(Ok(()), set.union_with_diff(&'a', &'c', 5));
+	assert_eq!(Ok(()), set.union_with_diff(&'c', &'a', -5));
+	assert_eq!(6, set.subset_count());
+}
+
+#[test]
+fn contradiction_err() {
+	let mut set = letters();
+	set.union_with_diff(&'a', &'b', 2).unwrap();
+	set.union_with_diff(&'b', &'c', 3).unwrap();
+
+	assert_eq!(
+		Err(RelationError::Contradiction),
+		set.union_with_diff(&'a', &'c', 4)
+	);
+	assert_eq!(
+		Err(RelationError::Contradiction),
+		set.union_with_diff(&'a', &'a', 1)
+	);
+	assert_eq!(Ok(Some(5)), set.diff(&'a', &'c'));
+}
+
+#[test]
+fn long_paths_keep_potentials() {
+	let mut set: WeightedHashDisjointSet<u32, i64> = (0..1_000).collect();
+	for i in 1..1_000 {
+		// merging singletons onto the growing subset alternates sides of the link
+		set.union_with_diff(&i, &(i - 1), i as i64).unwrap();
+	}
+
+	let expected = (1..1_000).sum::<i64>();
+	assert_eq!(Ok(Some(expected)), set.diff(&999, &0));
+	assert_eq!(Ok(Some(-expected)), set.diff(&0, &999));
+	assert_eq!(Ok(Some(999)), set.diff(&999, &998));
+	assert_eq!(1, set.subset_count());
+}
+
+#[test]
+fn same_subset() {
+	let mut set = letters();
+	set.union_with_diff(&'h', &'a', 0).unwrap();
+	assert_eq!(Ok(true), set.same_subset(&'a', &'h'));
+	assert_eq!(Ok(false), set.same_subset(&'a', &'b'));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rotation(u8);
+
+impl Group for Rotation {
+	fn identity() -> Self { Rotation(0) }
+
+	fn combine(&self, other: &Self) -> Self { Rotation((self.0 + other.0) % 4) }
+
+	fn inverse(&self) -> Self { Rotation((4 - self.0) % 4) }
+}
+
+#[test]
+fn custom_group() {
+	let mut set: WeightedHashDisjointSet<&str, Rotation> =
+		vec!["n", "e", "s"].into_iter().collect();
+	set.union_with_diff("e", "n", Rotation(1)).unwrap();
+	set.union_with_diff("s", "e", Rotation(1)).unwrap();
+
+	assert_eq!(Ok(Some(Rotation(2))), set.diff("s", "n"));
+	assert_eq!(Ok(Some(Rotation(2))), set.diff("n", "s"));
+	assert_eq!(
+		Err(RelationError::Contradiction),
+		set.union_with_diff("n", "s", Rotation(1))
+	);
+}